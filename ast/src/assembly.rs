@@ -13,25 +13,36 @@ pub enum AssemblyItem<'ast> {
     AssemblyLocalBinding(AssemblyLocalBinding<'ast>),
     AssemblyAssignment(AssemblyAssignment<'ast>),
     AssemblyLabel(AssemblyLabel<'ast>),
+    AssemblyIf(AssemblyIf<'ast>),
+    AssemblyForLoop(AssemblyForLoop<'ast>),
+    AssemblySwitch(AssemblySwitch<'ast>),
+    AssemblyFunctionDefinition(AssemblyFunctionDefinition<'ast>),
+    AssemblyMemberAccess(AssemblyMemberAccess<'ast>),
     NumberLiteral(Primitive<'ast>),
+    StringLiteral(StringLiteral<'ast>),
+    HexLiteral(StringLiteral<'ast>),
+    BoolLiteral(bool),
 
-    // FIXME
-    StringLiteral,
+    /// Yul `break`, only valid in the body of a for loop.
+    Break,
 
-    // FIXME,
-    HexLiteral,
+    /// Yul `continue`, only valid in the body of a for loop.
+    Continue,
+
+    /// Yul `leave`, only valid in the body of a function definition.
+    Leave,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct AssemblyLocalBinding<'ast> {
     pub id: IdentifierNode<'ast>,
-    pub init: FunctionalAssemblyExpressionNode<'ast>,
+    pub init: Option<AssemblyItemNode<'ast>>,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct AssemblyAssignment<'ast> {
     pub id: IdentifierNode<'ast>,
-    pub init: FunctionalAssemblyExpressionNode<'ast>,
+    pub init: AssemblyItemNode<'ast>,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -39,6 +50,49 @@ pub struct AssemblyLabel<'ast> {
     pub id: IdentifierNode<'ast>,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AssemblyIf<'ast> {
+    pub test: AssemblyItemNode<'ast>,
+    pub body: InlineAssemblyBlockNode<'ast>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AssemblyForLoop<'ast> {
+    pub init: InlineAssemblyBlockNode<'ast>,
+    pub test: AssemblyItemNode<'ast>,
+    pub update: InlineAssemblyBlockNode<'ast>,
+    pub body: InlineAssemblyBlockNode<'ast>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AssemblySwitch<'ast> {
+    pub discriminant: AssemblyItemNode<'ast>,
+    pub cases: AssemblyCaseList<'ast>,
+}
+
+/// A single `case` of an `AssemblySwitch`, `test` is `None` for `default`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AssemblyCase<'ast> {
+    pub test: Option<AssemblyItemNode<'ast>>,
+    pub body: InlineAssemblyBlockNode<'ast>,
+}
+
+/// Yul `function name(params) -> returns { ... }`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AssemblyFunctionDefinition<'ast> {
+    pub name: IdentifierNode<'ast>,
+    pub params: IdentifierList<'ast>,
+    pub returns: IdentifierList<'ast>,
+    pub body: InlineAssemblyBlockNode<'ast>,
+}
+
+/// Suffixed reference to a storage variable, such as `x.slot` or `x.offset`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AssemblyMemberAccess<'ast> {
+    pub object: IdentifierNode<'ast>,
+    pub member: IdentifierNode<'ast>,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct FunctionalAssemblyExpression<'ast> {
     pub id: IdentifierNode<'ast>,
@@ -49,6 +103,7 @@ pub type AssemblyItemNode<'ast> = Node<'ast, AssemblyItem<'ast>>;
 pub type AssemblyItemList<'ast> = NodeList<'ast, AssemblyItem<'ast>>;
 pub type FunctionalAssemblyExpressionNode<'ast> = Node<'ast, FunctionalAssemblyExpression<'ast>>;
 pub type InlineAssemblyBlockNode<'ast> = Node<'ast, InlineAssemblyBlock<'ast>>;
+pub type AssemblyCaseList<'ast> = NodeList<'ast, AssemblyCase<'ast>>;
 
 impl_from! {
     Identifier => AssemblyItem::Identifier,
@@ -57,5 +112,10 @@ impl_from! {
     AssemblyLocalBinding => AssemblyItem::AssemblyLocalBinding,
     AssemblyAssignment => AssemblyItem::AssemblyAssignment,
     AssemblyLabel => AssemblyItem::AssemblyLabel,
+    AssemblyIf => AssemblyItem::AssemblyIf,
+    AssemblyForLoop => AssemblyItem::AssemblyForLoop,
+    AssemblySwitch => AssemblyItem::AssemblySwitch,
+    AssemblyFunctionDefinition => AssemblyItem::AssemblyFunctionDefinition,
+    AssemblyMemberAccess => AssemblyItem::AssemblyMemberAccess,
     Primitive => AssemblyItem::NumberLiteral,
 }
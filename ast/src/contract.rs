@@ -2,11 +2,19 @@ use {*};
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct ContractDefinition<'ast> {
+    pub is_abstract: Option<FlagNode<'ast>>,
     pub name: IdentifierNode<'ast>,
-    pub inherits: IdentifierList<'ast>,
+    pub inherits: InheritanceSpecifierList<'ast>,
     pub body: ContractPartList<'ast>,
 }
 
+/// Base contract in the `is` list, optionally with constructor arguments.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct InheritanceSpecifier<'ast> {
+    pub name: IdentifierNode<'ast>,
+    pub arguments: ExpressionList<'ast>,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum ContractPart<'ast> {
     StateVariableDeclaration(StateVariableDeclaration<'ast>),
@@ -14,7 +22,10 @@ pub enum ContractPart<'ast> {
     StructDefinition(StructDefinition<'ast>),
     ModifierDefinition(ModifierDefinition<'ast>),
     FunctionDefinition(FunctionDefinition<'ast>),
+    ConstructorDefinition(ConstructorDefinition<'ast>),
+    SpecialFunctionDefinition(SpecialFunctionDefinition<'ast>),
     EventDefinition(EventDefinition<'ast>),
+    ErrorDefinition(ErrorDefinition<'ast>),
     EnumDefinition(EnumDefinition<'ast>),
 }
 
@@ -36,8 +47,40 @@ pub enum StateVariableVisibility {
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct UsingForDeclaration<'ast> {
-    pub id: IdentifierNode<'ast>,
+    /// Library name, `None` when a braced list of functions is used instead.
+    pub id: Option<IdentifierNode<'ast>>,
+    pub functions: NodeList<'ast, UsingFunction<'ast>>,
     pub type_name: Option<TypeNameNode<'ast>>,
+
+    /// Only allowed at file level, applies the functions everywhere the type is used.
+    pub global: Option<FlagNode<'ast>>,
+}
+
+/// `f` or `f as +` in `using {f, g as +} for T;`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UsingFunction<'ast> {
+    pub function: IdentifierNode<'ast>,
+    pub operator: Option<Node<'ast, UserOperator>>,
+}
+
+/// Operators that can be bound to functions on user defined value types.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum UserOperator {
+    BitAnd,
+    BitOr,
+    BitXor,
+    BitNot,
+    Addition,
+    Subtraction,
+    Multiplication,
+    Division,
+    Remainder,
+    Equality,
+    Inequality,
+    Lesser,
+    LesserEquals,
+    Greater,
+    GreaterEquals,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -50,9 +93,18 @@ pub struct StructDefinition<'ast> {
 pub struct ModifierDefinition<'ast> {
     pub name: IdentifierNode<'ast>,
     pub params: ParameterList<'ast>,
+    pub is_virtual: Option<FlagNode<'ast>>,
+    pub overrides: Option<OverrideSpecifierNode<'ast>>,
     pub block: BlockNode<'ast>,
 }
 
+/// `override`, optionally followed by a list of overridden bases:
+/// `override(A, B)`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OverrideSpecifier<'ast> {
+    pub bases: IdentifierList<'ast>,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct EventDefinition<'ast> {
     pub anonymous: Option<FlagNode<'ast>>,
@@ -60,6 +112,13 @@ pub struct EventDefinition<'ast> {
     pub params: IndexedParameterList<'ast>,
 }
 
+/// Custom error, can be declared in contracts as well as at file level.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ErrorDefinition<'ast> {
+    pub name: IdentifierNode<'ast>,
+    pub params: ParameterList<'ast>,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct IndexedParameter<'ast> {
     pub type_name: TypeNameNode<'ast>,
@@ -75,7 +134,9 @@ pub struct EnumDefinition<'ast> {
 
 pub type ContractPartNode<'ast> = Node<'ast, ContractPart<'ast>>;
 pub type ContractPartList<'ast> = NodeList<'ast, ContractPart<'ast>>;
+pub type InheritanceSpecifierList<'ast> = NodeList<'ast, InheritanceSpecifier<'ast>>;
 pub type IndexedParameterList<'ast> = NodeList<'ast, IndexedParameter<'ast>>;
+pub type OverrideSpecifierNode<'ast> = Node<'ast, OverrideSpecifier<'ast>>;
 
 impl_from! {
     StateVariableDeclaration => ContractPart::StateVariableDeclaration,
@@ -83,6 +144,9 @@ impl_from! {
     StructDefinition => ContractPart::StructDefinition,
     ModifierDefinition => ContractPart::ModifierDefinition,
     FunctionDefinition => ContractPart::FunctionDefinition,
+    ConstructorDefinition => ContractPart::ConstructorDefinition,
+    SpecialFunctionDefinition => ContractPart::SpecialFunctionDefinition,
     EventDefinition => ContractPart::EventDefinition,
+    ErrorDefinition => ContractPart::ErrorDefinition,
     EnumDefinition => ContractPart::EnumDefinition,
 }
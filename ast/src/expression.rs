@@ -1,3 +1,5 @@
+use toolshed::list::List;
+
 use {*};
 
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -11,10 +13,14 @@ pub enum Expression<'ast> {
     AssignmentExpression(AssignmentExpression<'ast>),
     TupleExpression(TupleExpression<'ast>),
     CallExpression(CallExpression<'ast>),
+    NamedCallExpression(NamedCallExpression<'ast>),
+    CallOptionsExpression(CallOptionsExpression<'ast>),
     MemberAccessExpression(MemberAccessExpression<'ast>),
     IndexAccessExpression(IndexAccessExpression<'ast>),
     ConditionalExpression(ConditionalExpression<'ast>),
     ElementaryTypeExpression(ElementaryTypeName),
+    TypeExpression(TypeExpression<'ast>),
+    NewExpression(NewExpression<'ast>),
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -23,7 +29,17 @@ pub enum Primitive<'ast> {
     HexNumber(&'ast str),
     IntegerNumber(&'ast str, NumberUnit),
     RationalNumber(&'ast str),
+
     String(&'ast str),
+
+    /// Same as `String`, but for `hex"..."` literals.
+    HexString(&'ast str),
+
+    /// Adjacent string literals, each piece is kept as is.
+    StringConcatenation(StringLiteralList<'ast>),
+
+    /// Same as `StringConcatenation`, but for `hex"..."` literals.
+    HexStringConcatenation(StringLiteralList<'ast>),
 }
 
 // TODO: Exact units
@@ -80,6 +96,7 @@ pub enum BinaryOperator {
     Subtraction,
     BitShiftLeft,
     BitShiftRight,
+    BitShiftRightUnsigned,
     Lesser,
     LesserEquals,
     Greater,
@@ -103,6 +120,7 @@ pub enum AssignmentOperator {
     Remainder,
     BitShiftLeft,
     BitShiftRight,
+    BitShiftRightUnsigned,
     BitAnd,
     BitXor,
     BitOr,
@@ -134,9 +152,10 @@ pub struct AssignmentExpression<'ast> {
     pub right: ExpressionNode<'ast>,
 }
 
+/// Elements of a tuple can be omitted, as in `(a, , c) = f()`.
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct TupleExpression<'ast> {
-    pub expressions: ExpressionList<'ast>,
+    pub expressions: List<'ast, Option<ExpressionNode<'ast>>>,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -145,6 +164,26 @@ pub struct CallExpression<'ast> {
     pub arguments: ExpressionList<'ast>,
 }
 
+/// `callee({a: 1, b: 2})`, a call with arguments passed by name.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NamedCallExpression<'ast> {
+    pub callee: ExpressionNode<'ast>,
+    pub arguments: NamedArgumentList<'ast>,
+}
+
+/// `callee{value: 1, gas: 2}`, the options passed to an external call.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CallOptionsExpression<'ast> {
+    pub callee: ExpressionNode<'ast>,
+    pub options: NamedArgumentList<'ast>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NamedArgument<'ast> {
+    pub name: IdentifierNode<'ast>,
+    pub value: ExpressionNode<'ast>,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct MemberAccessExpression<'ast> {
     pub object: ExpressionNode<'ast>,
@@ -164,10 +203,23 @@ pub struct ConditionalExpression<'ast> {
     pub alternate: ExpressionNode<'ast>,
 }
 
+/// `type(T)`, used to access type information such as `type(uint8).max`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TypeExpression<'ast> {
+    pub type_name: TypeNameNode<'ast>,
+}
+
+/// `new T`, usually called right away, as in `new uint[](10)`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NewExpression<'ast> {
+    pub type_name: TypeNameNode<'ast>,
+}
+
 pub use self::Expression::ThisExpression;
 
 pub type ExpressionNode<'ast> = Node<'ast, Expression<'ast>>;
 pub type ExpressionList<'ast> = NodeList<'ast, Expression<'ast>>;
+pub type NamedArgumentList<'ast> = NodeList<'ast, NamedArgument<'ast>>;
 
 impl<'ast> From<ElementaryTypeName> for Expression<'ast> {
     #[inline]
@@ -185,7 +237,11 @@ impl_from! {
     AssignmentExpression => Expression::AssignmentExpression,
     TupleExpression => Expression::TupleExpression,
     CallExpression => Expression::CallExpression,
+    NamedCallExpression => Expression::NamedCallExpression,
+    CallOptionsExpression => Expression::CallOptionsExpression,
     MemberAccessExpression => Expression::MemberAccessExpression,
     IndexAccessExpression => Expression::IndexAccessExpression,
     ConditionalExpression => Expression::ConditionalExpression,
+    TypeExpression => Expression::TypeExpression,
+    NewExpression => Expression::NewExpression,
 }
@@ -6,11 +6,44 @@ pub struct FunctionDefinition<'ast> {
     pub params: ParameterList<'ast>,
     pub visibility: Option<Node<'ast, FunctionVisibility>>,
     pub mutability: Option<Node<'ast, StateMutability>>,
+    pub is_virtual: Option<FlagNode<'ast>>,
+    pub overrides: Option<OverrideSpecifierNode<'ast>>,
     pub modifiers: ModifierInvocationList<'ast>,
     pub returns: ParameterList<'ast>,
     pub block: Option<BlockNode<'ast>>,
 }
 
+/// The `receive` and `fallback` functions.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SpecialFunctionDefinition<'ast> {
+    pub kind: SpecialFunction,
+    pub params: ParameterList<'ast>,
+    pub visibility: Option<Node<'ast, FunctionVisibility>>,
+    pub mutability: Option<Node<'ast, StateMutability>>,
+    pub is_virtual: Option<FlagNode<'ast>>,
+    pub overrides: Option<OverrideSpecifierNode<'ast>>,
+    pub modifiers: ModifierInvocationList<'ast>,
+    pub returns: ParameterList<'ast>,
+    pub block: Option<BlockNode<'ast>>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SpecialFunction {
+    Receive,
+    Fallback,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ConstructorDefinition<'ast> {
+    pub params: ParameterList<'ast>,
+
+    /// Only allowed in legacy code, kept so that tooling can warn about it.
+    pub visibility: Option<Node<'ast, FunctionVisibility>>,
+    pub mutability: Option<Node<'ast, StateMutability>>,
+    pub modifiers: ModifierInvocationList<'ast>,
+    pub block: BlockNode<'ast>,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum FunctionVisibility {
     External,
@@ -30,12 +63,15 @@ pub enum StateMutability {
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct ModifierInvocation<'ast> {
     pub id: IdentifierNode<'ast>,
-    pub arguments: ExpressionList<'ast>,
+
+    /// `None` if the modifier is invoked without parentheses.
+    pub arguments: Option<ExpressionList<'ast>>,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Parameter<'ast> {
     pub type_name: TypeNameNode<'ast>,
+    pub location: Option<Node<'ast, StorageLocation>>,
     pub name: Option<IdentifierNode<'ast>>,
 }
 
@@ -15,6 +15,8 @@ mod expression;
 mod statement;
 mod assembly;
 
+pub mod summary;
+
 use toolshed::list::{List, UnsafeList};
 use toolshed::Arena;
 use std::marker::PhantomData;
@@ -33,6 +35,41 @@ pub use self::assembly::*;
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Flag;
 
+/// A comment skipped over by the lexer, only collected when requested.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Comment {
+    pub kind: CommentKind,
+    pub start: u32,
+    pub end: u32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CommentKind {
+    /// `// ...`
+    Line,
+
+    /// `/* ... */`
+    Block,
+
+    /// `/// ...` or `/** ... */`
+    Doc,
+}
+
+/// A problem in the source that doesn't prevent it from being parsed,
+/// only reported when requested.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Warning {
+    pub kind: WarningKind,
+    pub start: u32,
+    pub end: u32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WarningKind {
+    /// Address literal with letter casing not matching its EIP-55 checksum
+    AddressChecksum,
+}
+
 pub type Identifier<'ast> = &'ast str;
 pub type StringLiteral<'ast> = &'ast str;
 pub type VersionLiteral<'ast> = &'ast str;
@@ -44,6 +81,7 @@ pub type SourceUnitList<'ast> = NodeList<'ast, SourceUnit<'ast>>;
 pub type IdentifierNode<'ast> = Node<'ast, Identifier<'ast>>;
 pub type IdentifierList<'ast> = NodeList<'ast, Identifier<'ast>>;
 pub type StringLiteralNode<'ast> = Node<'ast, StringLiteral<'ast>>;
+pub type StringLiteralList<'ast> = NodeList<'ast, StringLiteral<'ast>>;
 
 
 /// A Solidity source code parsed to an AST
@@ -55,6 +93,12 @@ pub struct Program<'ast> {
     /// `Arena` on which the entire AST is allocated.
     arena: Arena,
 
+    /// Comments found in the source, if they were collected.
+    comments: Vec<Comment>,
+
+    /// Warnings found in the source, if they were checked for.
+    warnings: Vec<Warning>,
+
     /// For lifetime safety :).
     _phantom: PhantomData<SourceUnitList<'ast>>
 }
@@ -65,16 +109,44 @@ impl<'ast> Program<'ast> {
         Program {
             body,
             arena,
+            comments: Vec::new(),
+            warnings: Vec::new(),
             _phantom: PhantomData,
         }
     }
 
+    /// Attach comments collected from the source.
+    #[inline]
+    pub fn with_comments(mut self, comments: Vec<Comment>) -> Self {
+        self.comments = comments;
+        self
+    }
+
+    /// Attach warnings found in the source.
+    #[inline]
+    pub fn with_warnings(mut self, warnings: Vec<Warning>) -> Self {
+        self.warnings = warnings;
+        self
+    }
+
     /// Get the list of `SourceUnit`s.
     #[inline]
     pub fn body(&self) -> SourceUnitList<'ast> {
         unsafe { self.body.into_list() }
     }
 
+    /// Get the comments, sorted by their position in the source.
+    #[inline]
+    pub fn comments(&self) -> &[Comment] {
+        &self.comments
+    }
+
+    /// Get the warnings, sorted by their position in the source.
+    #[inline]
+    pub fn warnings(&self) -> &[Warning] {
+        &self.warnings
+    }
+
     /// Get a reference to the `Arena` on which the AST is allocated.
     #[inline]
     pub fn arena(&'ast self) -> &'ast Arena {
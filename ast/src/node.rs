@@ -1,5 +1,5 @@
 use toolshed::CopyCell;
-use std::ops::Deref;
+use std::ops::{Deref, Range};
 use std::fmt::{self, Debug};
 
 pub trait OptionalLocation {
@@ -10,18 +10,12 @@ pub trait OptionalLocation {
 impl<'ast, T> OptionalLocation for Option<Node<'ast, T>> {
     #[inline]
     fn start(&self) -> Option<u32> {
-        match *self {
-            Some(ref node) => Some(node.start),
-            None           => None,
-        }
+        self.as_ref().map(|node| node.start)
     }
 
     #[inline]
     fn end(&self) -> Option<u32> {
-        match *self {
-            Some(ref node) => Some(node.end),
-            None           => None,
-        }
+        self.as_ref().map(|node| node.end)
     }
 }
 
@@ -48,6 +42,22 @@ impl<T> NodeInner<T> {
             value,
         }
     }
+
+    /// Location of the node in the source as a byte range.
+    #[inline]
+    pub fn span(&self) -> Range<usize> {
+        self.start as usize..self.end as usize
+    }
+}
+
+/// Allows accessing fields of the value directly, `node.name` instead of `node.value.name`.
+impl<T> Deref for NodeInner<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.value
+    }
 }
 
 impl<'ast, T: 'ast> Node<'ast, T> {
@@ -101,7 +111,7 @@ impl<T: Debug> Debug for NodeInner<T> {
 }
 
 #[cfg(test)]
-mod node {
+mod test {
     use super::*;
 
     #[test]
@@ -110,7 +120,7 @@ mod node {
         let bar = NodeInner::new(0, 0, "bar");
 
         let foo_ptr = Node::new(&foo);
-        let bar_ptr = foo_ptr.clone();
+        let bar_ptr = foo_ptr;
 
         assert_eq!(*foo_ptr, NodeInner::new(0, 0, "foo"));
         assert_eq!(*bar_ptr, NodeInner::new(0, 0, "foo"));
@@ -4,8 +4,15 @@ use {*};
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum SourceUnit<'ast> {
     PragmaDirective(PragmaDirective<'ast>),
+    ExperimentalPragma(ExperimentalPragma<'ast>),
+    AbicoderPragma(AbicoderPragma<'ast>),
     ImportDirective(ImportDirective<'ast>),
     ContractDefinition(ContractDefinition<'ast>),
+    EventDefinition(EventDefinition<'ast>),
+    ErrorDefinition(ErrorDefinition<'ast>),
+    UsingForDeclaration(UsingForDeclaration<'ast>),
+    FunctionDefinition(FunctionDefinition<'ast>),
+    StateVariableDeclaration(StateVariableDeclaration<'ast>),
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -13,6 +20,18 @@ pub struct PragmaDirective<'ast> {
     pub version: &'ast str,
 }
 
+/// 'pragma' 'experimental' ( Identifier | StringLiteral ) ';'
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ExperimentalPragma<'ast> {
+    pub feature: IdentifierNode<'ast>,
+}
+
+/// 'pragma' 'abicoder' Identifier ';'
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AbicoderPragma<'ast> {
+    pub version: IdentifierNode<'ast>,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Import<'ast> {
     pub symbol: IdentifierNode<'ast>,
@@ -27,13 +46,19 @@ pub enum ImportDirective<'ast> {
         alias: Option<IdentifierNode<'ast>>,
     },
 
-    /// 'import' ('*' | Identifier) ('as' Identifier)? 'from' StringLiteral ';'
+    /// 'import' ('*' | Identifier ('as' Identifier)?) 'from' StringLiteral ';'
     From {
         symbol: Option<IdentifierNode<'ast>>,
         alias: Option<IdentifierNode<'ast>>,
         source: Node<'ast, StringLiteral<'ast>>,
     },
 
+    /// 'import' '*' 'as' Identifier 'from' StringLiteral ';'
+    Namespace {
+        alias: IdentifierNode<'ast>,
+        source: Node<'ast, StringLiteral<'ast>>,
+    },
+
     /// 'import' '{' Identifier ('as' Identifier)? ( ',' Identifier ('as' Identifier)? )* '}' 'from' StringLiteral ';'
     ManyFrom {
         imports: NodeList<'ast, Import<'ast>>,
@@ -43,6 +68,13 @@ pub enum ImportDirective<'ast> {
 
 impl_from! {
     PragmaDirective => SourceUnit::PragmaDirective,
+    ExperimentalPragma => SourceUnit::ExperimentalPragma,
+    AbicoderPragma => SourceUnit::AbicoderPragma,
     ImportDirective => SourceUnit::ImportDirective,
     ContractDefinition => SourceUnit::ContractDefinition,
+    EventDefinition => SourceUnit::EventDefinition,
+    ErrorDefinition => SourceUnit::ErrorDefinition,
+    UsingForDeclaration => SourceUnit::UsingForDeclaration,
+    FunctionDefinition => SourceUnit::FunctionDefinition,
+    StateVariableDeclaration => SourceUnit::StateVariableDeclaration,
 }
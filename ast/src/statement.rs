@@ -10,12 +10,16 @@ pub enum Statement<'ast> {
     WhileStatement(WhileStatement<'ast>),
     ForStatement(ForStatement<'ast>),
     BlockStatement(Block<'ast>),
+    UncheckedBlockStatement(UncheckedBlockStatement<'ast>),
     InlineAssemblyStatement(InlineAssemblyStatement<'ast>),
+    TryStatement(TryStatement<'ast>),
+    EmitStatement(EmitStatement<'ast>),
     DoWhileStatement(DoWhileStatement<'ast>),
     ContinueStatement,
     BreakStatement,
     ReturnStatement(ReturnStatement<'ast>),
     ThrowStatement,
+    EmptyStatement,
     VariableDefinitionStatement(VariableDefinitionStatement<'ast>),
     InferredDefinitionStatement(InferredDefinitionStatement<'ast>),
     ExpressionStatement(ExpressionNode<'ast>),
@@ -55,12 +59,42 @@ pub struct Block<'ast> {
     pub body: StatementList<'ast>,
 }
 
+/// `unchecked { ... }`, arithmetic inside the block doesn't revert on overflow.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UncheckedBlockStatement<'ast> {
+    pub block: BlockNode<'ast>,
+}
+
+/// `assembly "evmasm" ("memory-safe") { ... }`, both the dialect string
+/// and the flags are optional.
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct InlineAssemblyStatement<'ast> {
     pub string: Option<StringLiteralNode<'ast>>,
+    pub flags: StringLiteralList<'ast>,
     pub block: InlineAssemblyBlockNode<'ast>,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TryStatement<'ast> {
+    pub expression: ExpressionNode<'ast>,
+    pub returns: ParameterList<'ast>,
+    pub body: BlockNode<'ast>,
+    pub catches: CatchClauseList<'ast>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CatchClause<'ast> {
+    pub name: Option<IdentifierNode<'ast>>,
+    pub params: ParameterList<'ast>,
+    pub body: BlockNode<'ast>,
+}
+
+/// `emit Event(...)`, the event can also be qualified, as in `emit Lib.Event(...)`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EmitStatement<'ast> {
+    pub call: ExpressionNode<'ast>,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct DoWhileStatement<'ast> {
     pub body: StatementNode<'ast>,
@@ -86,12 +120,13 @@ pub struct InferredDefinitionStatement<'ast> {
     pub init: ExpressionNode<'ast>,
 }
 
-pub use self::Statement::{Placeholder, BreakStatement, ContinueStatement, ThrowStatement};
+pub use self::Statement::{Placeholder, BreakStatement, ContinueStatement, ThrowStatement, EmptyStatement};
 
 pub type StatementNode<'ast> = Node<'ast, Statement<'ast>>;
 pub type StatementList<'ast> = NodeList<'ast, Statement<'ast>>;
 pub type SimpleStatementNode<'ast> = Node<'ast, SimpleStatement<'ast>>;
 pub type BlockNode<'ast> = Node<'ast, Block<'ast>>;
+pub type CatchClauseList<'ast> = NodeList<'ast, CatchClause<'ast>>;
 
 impl_from! {
     IfStatement => Statement::IfStatement,
@@ -106,5 +141,8 @@ impl_from! {
     ExpressionNode => Statement::ExpressionStatement,
     ExpressionNode => SimpleStatement::ExpressionStatement,
     Block => Statement::BlockStatement,
+    UncheckedBlockStatement => Statement::UncheckedBlockStatement,
     InlineAssemblyStatement => Statement::InlineAssemblyStatement,
+    TryStatement => Statement::TryStatement,
+    EmitStatement => Statement::EmitStatement,
 }
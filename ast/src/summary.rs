@@ -0,0 +1,253 @@
+//! Owned summary of the AST, covering only the declarations.
+//!
+//! The regular AST borrows from the `Arena` it was allocated on, which makes it
+//! impossible to store or move across threads without keeping the `Arena` alive.
+//! Types in this module own all of their data instead, but they are not a full
+//! mirror of the AST. Only contracts, state variables, events and type names
+//! are kept. Expressions are reduced to their locations, and all other source
+//! units and contract parts to `Other` nodes that only keep their location.
+//! Anything that needs function bodies, statements or expressions has to work
+//! with the regular AST while the `Arena` is alive.
+
+/// Owned counterpart of `NodeInner`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Node<T> {
+    pub start: u32,
+    pub end: u32,
+    pub value: T,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Program {
+    pub body: Vec<Node<SourceUnit>>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum SourceUnit {
+    ContractDefinition(ContractDefinition),
+    EventDefinition(EventDefinition),
+
+    /// Any other source unit, only its location is kept.
+    Other,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContractDefinition {
+    pub is_abstract: Option<Node<::Flag>>,
+    pub name: Node<String>,
+    pub inherits: Vec<Node<InheritanceSpecifier>>,
+    pub body: Vec<Node<ContractPart>>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct InheritanceSpecifier {
+    pub name: Node<String>,
+
+    /// Expressions aren't mirrored, only the locations of the arguments are kept.
+    pub arguments: Vec<Node<()>>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ContractPart {
+    StateVariableDeclaration(StateVariableDeclaration),
+    EventDefinition(EventDefinition),
+
+    /// Any other contract part, including functions and modifiers, only its
+    /// location is kept.
+    Other,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct StateVariableDeclaration {
+    pub type_name: Node<TypeName>,
+    pub visibility: Option<Node<::StateVariableVisibility>>,
+    pub constant: Option<Node<::Flag>>,
+    pub name: Node<String>,
+
+    /// Expressions aren't mirrored, only the location of the initializer is kept.
+    pub init: Option<Node<()>>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct EventDefinition {
+    pub anonymous: Option<Node<::Flag>>,
+    pub name: Node<String>,
+    pub params: Vec<Node<IndexedParameter>>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct IndexedParameter {
+    pub type_name: Node<TypeName>,
+    pub indexed: Option<Node<::Flag>>,
+    pub name: Option<Node<String>>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum TypeName {
+    ElementaryTypeName(::ElementaryTypeName),
+    UserDefinedTypeName(String),
+    Mapping(Box<Mapping>),
+    ArrayTypeName(Box<ArrayTypeName>),
+    FunctionTypeName,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Mapping {
+    pub from: Node<TypeName>,
+    pub from_name: Option<Node<String>>,
+    pub to: Node<TypeName>,
+    pub to_name: Option<Node<String>>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ArrayTypeName {
+    pub type_name: Node<TypeName>,
+
+    /// Expressions aren't mirrored, only the location of the length is kept.
+    pub length: Option<Node<()>>,
+}
+
+impl<'ast, T, U> From<::Node<'ast, T>> for Node<U>
+where
+    T: 'ast + Copy,
+    U: From<T>,
+{
+    #[inline]
+    fn from(node: ::Node<'ast, T>) -> Self {
+        Node {
+            start: node.start,
+            end: node.end,
+            value: node.value.into(),
+        }
+    }
+}
+
+#[inline]
+fn list<'ast, T, U>(list: ::NodeList<'ast, T>) -> Vec<Node<U>>
+where
+    T: 'ast + Copy,
+    U: From<T>,
+{
+    list.iter().map(|node| (*node).into()).collect()
+}
+
+#[inline]
+fn span<'ast, T: 'ast>(node: ::Node<'ast, T>) -> Node<()> {
+    Node {
+        start: node.start,
+        end: node.end,
+        value: (),
+    }
+}
+
+impl<'a, 'ast> From<&'a ::Program<'ast>> for Program {
+    fn from(program: &'a ::Program<'ast>) -> Self {
+        Program {
+            body: list(program.body()),
+        }
+    }
+}
+
+impl<'ast> From<::SourceUnit<'ast>> for SourceUnit {
+    fn from(unit: ::SourceUnit<'ast>) -> Self {
+        match unit {
+            ::SourceUnit::ContractDefinition(contract) => SourceUnit::ContractDefinition(contract.into()),
+            ::SourceUnit::EventDefinition(event) => SourceUnit::EventDefinition(event.into()),
+            _ => SourceUnit::Other,
+        }
+    }
+}
+
+impl<'ast> From<::ContractDefinition<'ast>> for ContractDefinition {
+    fn from(contract: ::ContractDefinition<'ast>) -> Self {
+        ContractDefinition {
+            is_abstract: contract.is_abstract.map(Into::into),
+            name: contract.name.into(),
+            inherits: list(contract.inherits),
+            body: list(contract.body),
+        }
+    }
+}
+
+impl<'ast> From<::InheritanceSpecifier<'ast>> for InheritanceSpecifier {
+    fn from(specifier: ::InheritanceSpecifier<'ast>) -> Self {
+        InheritanceSpecifier {
+            name: specifier.name.into(),
+            arguments: specifier.arguments.iter().map(|argument| span(*argument)).collect(),
+        }
+    }
+}
+
+impl<'ast> From<::ContractPart<'ast>> for ContractPart {
+    fn from(part: ::ContractPart<'ast>) -> Self {
+        match part {
+            ::ContractPart::StateVariableDeclaration(declaration) => ContractPart::StateVariableDeclaration(declaration.into()),
+            ::ContractPart::EventDefinition(event) => ContractPart::EventDefinition(event.into()),
+            _ => ContractPart::Other,
+        }
+    }
+}
+
+impl<'ast> From<::StateVariableDeclaration<'ast>> for StateVariableDeclaration {
+    fn from(declaration: ::StateVariableDeclaration<'ast>) -> Self {
+        StateVariableDeclaration {
+            type_name: declaration.type_name.into(),
+            visibility: declaration.visibility.map(Into::into),
+            constant: declaration.constant.map(Into::into),
+            name: declaration.name.into(),
+            init: declaration.init.map(span),
+        }
+    }
+}
+
+impl<'ast> From<::EventDefinition<'ast>> for EventDefinition {
+    fn from(event: ::EventDefinition<'ast>) -> Self {
+        EventDefinition {
+            anonymous: event.anonymous.map(Into::into),
+            name: event.name.into(),
+            params: list(event.params),
+        }
+    }
+}
+
+impl<'ast> From<::IndexedParameter<'ast>> for IndexedParameter {
+    fn from(param: ::IndexedParameter<'ast>) -> Self {
+        IndexedParameter {
+            type_name: param.type_name.into(),
+            indexed: param.indexed.map(Into::into),
+            name: param.name.map(Into::into),
+        }
+    }
+}
+
+impl<'ast> From<::TypeName<'ast>> for TypeName {
+    fn from(type_name: ::TypeName<'ast>) -> Self {
+        match type_name {
+            ::TypeName::ElementaryTypeName(elementary) => TypeName::ElementaryTypeName(elementary),
+            ::TypeName::UserDefinedTypeName(name) => TypeName::UserDefinedTypeName(name.into()),
+            ::TypeName::Mapping(mapping) => TypeName::Mapping(Box::new(mapping.into())),
+            ::TypeName::ArrayTypeName(array) => TypeName::ArrayTypeName(Box::new(array.into())),
+            ::TypeName::FunctionTypeName(_) => TypeName::FunctionTypeName,
+        }
+    }
+}
+
+impl<'ast> From<::Mapping<'ast>> for Mapping {
+    fn from(mapping: ::Mapping<'ast>) -> Self {
+        Mapping {
+            from: mapping.from.into(),
+            from_name: mapping.from_name.map(Into::into),
+            to: mapping.to.into(),
+            to_name: mapping.to_name.map(Into::into),
+        }
+    }
+}
+
+impl<'ast> From<::ArrayTypeName<'ast>> for ArrayTypeName {
+    fn from(array: ::ArrayTypeName<'ast>) -> Self {
+        ArrayTypeName {
+            type_name: array.type_name.into(),
+            length: array.length.map(span),
+        }
+    }
+}
@@ -5,8 +5,8 @@ pub enum TypeName<'ast> {
     ElementaryTypeName(ElementaryTypeName),
     UserDefinedTypeName(Identifier<'ast>),
     Mapping(Mapping<'ast>),
-    ArrayTypeName,
-    FunctionTypeName,
+    ArrayTypeName(ArrayTypeName<'ast>),
+    FunctionTypeName(FunctionTypeName<'ast>),
 }
 
 #[derive(Clone, Copy, PartialEq, Debug)]
@@ -20,11 +20,13 @@ pub struct VariableDeclaration<'ast> {
 pub enum StorageLocation {
     Memory,
     Storage,
+    Calldata,
 }
 
 #[derive(Clone, Copy, PartialEq, Debug)]
 pub enum ElementaryTypeName {
     Address,
+    AddressPayable,
     Bool,
     String,
     Bytes,
@@ -37,8 +39,24 @@ pub enum ElementaryTypeName {
 
 #[derive(Clone, Copy, PartialEq, Debug)]
 pub struct Mapping<'ast> {
-    pub from: ElementaryTypeNameNode<'ast>,
+    pub from: TypeNameNode<'ast>,
+    pub from_name: Option<IdentifierNode<'ast>>,
     pub to: TypeNameNode<'ast>,
+    pub to_name: Option<IdentifierNode<'ast>>,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct ArrayTypeName<'ast> {
+    pub type_name: TypeNameNode<'ast>,
+    pub length: Option<ExpressionNode<'ast>>,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct FunctionTypeName<'ast> {
+    pub params: ParameterList<'ast>,
+    pub visibility: Option<Node<'ast, FunctionVisibility>>,
+    pub mutability: Option<Node<'ast, StateMutability>>,
+    pub returns: ParameterList<'ast>,
 }
 
 pub type TypeNameNode<'ast> = Node<'ast, TypeName<'ast>>;
@@ -56,4 +74,6 @@ impl<'ast> From<ElementaryTypeName> for TypeName<'ast> {
 impl_from! {
     Identifier => TypeName::UserDefinedTypeName,
     Mapping => TypeName::Mapping,
+    ArrayTypeName => TypeName::ArrayTypeName,
+    FunctionTypeName => TypeName::FunctionTypeName,
 }
@@ -1,9 +1,11 @@
 extern crate logos;
 
 mod token;
+mod relex;
 
-pub use self::token::Token;
-pub use logos::{Logos, lookup};
+pub use self::token::{Token, TypeSize};
+pub use self::relex::{SpannedToken, tokenize, tokenize_with_capacity, relex};
+pub use logos::{Logos, Source, lookup};
 pub type Lexer<S> = logos::Lexer<Token, S>;
 
 // FIXME: This should probably be handled with a callback
@@ -11,11 +13,8 @@ pub type Lexer<S> = logos::Lexer<Token, S>;
 pub fn read_pragma<'source, S: logos::Source<'source>>(lex: &mut Lexer<S>) -> S::Slice {
     use logos::internal::LexerInternal;
 
-    loop {
-        match lex.read() {
-            0x01...0x20 => lex.bump(),
-            _           => break,
-        }
+    while let 0x01..=0x20 = lex.read() {
+        lex.bump();
     }
 
     let start = lex.range().start;
@@ -72,6 +71,43 @@ mod test {
         assert_lex("   ", []);
     }
 
+    #[test]
+    fn span_after_whitespace_run() {
+        let mut source = " \t\r\n".repeat(1000);
+
+        source.push_str("foo");
+
+        let mut lex = Token::lexer(source.as_str());
+
+        assert_eq!(lex.token, Identifier);
+        assert_eq!(lex.range(), 4000..4003);
+
+        lex.advance();
+
+        assert_eq!(lex.token, EndOfProgram);
+    }
+
+    #[test]
+    fn long_tokens() {
+        let identifier = "x".repeat(10_000);
+        let hex        = format!("0x{}", "f".repeat(9_998));
+        let source     = format!("{} {}", identifier, hex);
+
+        let mut lex = Token::lexer(source.as_str());
+
+        assert_eq!(lex.token, Identifier);
+        assert_eq!(lex.range(), 0..10_000);
+
+        lex.advance();
+
+        assert_eq!(lex.token, LiteralHex);
+        assert_eq!(lex.range(), 10_001..20_001);
+
+        lex.advance();
+
+        assert_eq!(lex.token, EndOfProgram);
+    }
+
     #[test]
     fn line_comment() {
         assert_lex(" // foo\nbar", [(Identifier, "bar")]);
@@ -87,6 +123,15 @@ mod test {
         assert_lex(" /* foo ", [(UnexpectedEndOfProgram, "/* foo ")]);
     }
 
+    #[test]
+    fn trivia_runs() {
+        assert_lex(
+            "foo\n                    // bar\n\t\t/* baz */\r\n          /* qux\n */ bar //",
+            [(Identifier, "foo"), (Identifier, "bar"), (EndOfProgram, "")]
+        );
+        assert_lex("foo\n          // bar\n  /* baz ", [(Identifier, "foo"), (UnexpectedEndOfProgram, "/* baz ")]);
+    }
+
     #[test]
     fn identifiers() {
         assert_lex(
@@ -109,6 +154,26 @@ mod test {
         );
     }
 
+    #[test]
+    fn dollar_in_identifiers() {
+        assert_lex("$$internal$$ a$b", [(Identifier, "$$internal$$"), (Identifier, "a$b")]);
+    }
+
+    #[test]
+    fn identifiers_are_ascii_only() {
+        assert_lex("$foo _bar", [(Identifier, "$foo"), (Identifier, "_bar")]);
+
+        let mut lex = Token::lexer("café");
+
+        assert_eq!(lex.token, Identifier);
+        assert_eq!(lex.slice(), "caf");
+
+        lex.advance();
+
+        assert_eq!(lex.token, UnexpectedToken);
+        assert_eq!(lex.range().start, 3);
+    }
+
     #[test]
     fn controls() {
         assert_lex(
@@ -159,32 +224,114 @@ mod test {
         );
     }
 
+    #[test]
+    fn leading_dot_rational() {
+        assert_lex(
+            ".5 x.5 x.y",
+             &[
+                (LiteralRational, ".5"),
+                (Identifier, "x"),
+                (LiteralRational, ".5"),
+                (Identifier, "x"),
+                (Accessor, "."),
+                (Identifier, "y"),
+            ][..]
+        );
+    }
+
+    #[test]
+    fn trailing_dot_integer() {
+        assert_lex(
+            "5. 5.; 5.e3 5.e-3 5.foo",
+             &[
+                (LiteralInteger, "5."),
+                (LiteralInteger, "5."),
+                (Semicolon, ";"),
+                (LiteralInteger, "5"),
+                (Accessor, "."),
+                (Identifier, "e3"),
+                (LiteralInteger, "5"),
+                (Accessor, "."),
+                (Identifier, "e"),
+                (OperatorSubtraction, "-"),
+                (LiteralInteger, "3"),
+                (LiteralInteger, "5"),
+                (Accessor, "."),
+                (Identifier, "foo"),
+            ][..]
+        );
+    }
+
+    #[test]
+    fn hex_prefix_case() {
+        let mut lex = Token::lexer("0xFF 0XFF");
+
+        assert_eq!(lex.token, LiteralHex);
+        assert_eq!(lex.range(), 0..4);
+
+        lex.advance();
+
+        assert_eq!(lex.token, LiteralHex);
+        assert_eq!(lex.range(), 5..9);
+
+        lex.advance();
+
+        assert_eq!(lex.token, EndOfProgram);
+    }
+
     #[test]
     fn strings() {
         assert_lex(r#"
             foo
             "\x19Ethereum Signed Message:\n47Please take my Ether and try to build Polkadot."
         "#,
-        &[
+        [
             (Identifier, "foo"),
             (LiteralString, r#""\x19Ethereum Signed Message:\n47Please take my Ether and try to build Polkadot.""#),
         ])
     }
 
+    #[test]
+    fn hex_strings() {
+        assert_lex(r#"
+            hex"00ff" hex'DEAD_beef' hex""
+            hex "00"
+        "#,
+        &[
+            (LiteralHexString, r#"hex"00ff""#),
+            (LiteralHexString, "hex'DEAD_beef'"),
+            (LiteralHexString, r#"hex"""#),
+            (KeywordHex, "hex"),
+            (LiteralString, r#""00""#),
+        ][..])
+    }
+
+    #[test]
+    fn hex_string_underscores() {
+        assert_lex(r#"hex"00_11_22""#, [(LiteralHexString, r#"hex"00_11_22""#)]);
+
+        // Underscores can only separate whole bytes
+        for source in &[r#"hex"0_011""#, r#"hex"_0011""#, r#"hex"0011_""#, r#"hex"00__11""#] {
+            assert_ne!(Token::lexer(*source).token, LiteralHexString, "{}", source);
+        }
+    }
+
     #[test]
     fn keywords() {
         assert_lex(
             "
-                anonymous as assembly break constant continue do
+                anonymous as assembly break calldata catch constant continue do
                 delete else external for hex if indexed internal import
                 is mapping memory new payable public pragma private pure
-                return returns storage super this throw using view while
+                return returns storage super this throw try type using view while
             ",
              &[
                 (KeywordAnonymous, "anonymous"),
                 (KeywordAs, "as"),
                 (KeywordAssembly, "assembly"),
                 (KeywordBreak, "break"),
+                (KeywordCalldata, "calldata"),
+                (KeywordCatch, "catch"),
                 (KeywordConstant, "constant"),
                 (KeywordContinue, "continue"),
                 (KeywordDo, "do"),
@@ -212,6 +359,8 @@ mod test {
                 (KeywordSuper, "super"),
                 (KeywordThis, "this"),
                 (KeywordThrow, "throw"),
+                (KeywordTry, "try"),
+                (KeywordType, "type"),
                 (KeywordUsing, "using"),
                 (KeywordView, "view"),
                 (KeywordWhile, "while"),
@@ -223,12 +372,13 @@ mod test {
     fn declarations() {
         assert_lex(
             "
-                var function event modifier struct
-                enum contract library interface
+                var function constructor event modifier
+                struct enum contract library interface
             ",
              &[
                 (DeclarationVar, "var"),
                 (DeclarationFunction, "function"),
+                (DeclarationConstructor, "constructor"),
                 (DeclarationEvent, "event"),
                 (DeclarationModifier, "modifier"),
                 (DeclarationStruct, "struct"),
@@ -266,15 +416,14 @@ mod test {
     fn reserved_words() {
         assert_lex(
             "
-                abstract after case catch default final in
+                abstract after case default final in
                 inline let match null of relocatable static
-                switch try type typeof
+                switch typeof
             ",
              &[
                 (ReservedWord, "abstract"),
                 (ReservedWord, "after"),
                 (ReservedWord, "case"),
-                (ReservedWord, "catch"),
                 (ReservedWord, "default"),
                 (ReservedWord, "final"),
                 (ReservedWord, "in"),
@@ -286,8 +435,6 @@ mod test {
                 (ReservedWord, "relocatable"),
                 (ReservedWord, "static"),
                 (ReservedWord, "switch"),
-                (ReservedWord, "try"),
-                (ReservedWord, "type"),
                 (ReservedWord, "typeof"),
             ][..]
         );
@@ -331,9 +478,9 @@ mod test {
     fn operators() {
         assert_lex(
             "
-                ++ -- ! ~ * / % ** + - << >>
+                ++ -- ! ~ * / % ** + - << >> >>>
                 < <= > >= == != & ^ | && || ?
-                = += -= *= /= %= <<= >>= &= ^= |=
+                = += -= *= /= %= <<= >>= >>>= &= ^= |=
             ",
              &[
                 (OperatorIncrement, "++"),
@@ -348,6 +495,7 @@ mod test {
                 (OperatorSubtraction, "-"),
                 (OperatorBitShiftLeft, "<<"),
                 (OperatorBitShiftRight, ">>"),
+                (OperatorBitShiftRightUnsigned, ">>>"),
                 (OperatorLesser, "<"),
                 (OperatorLesserEquals, "<="),
                 (OperatorGreater, ">"),
@@ -368,6 +516,7 @@ mod test {
                 (AssignRemainder, "%="),
                 (AssignBitShiftLeft, "<<="),
                 (AssignBitShiftRight, ">>="),
+                (AssignBitShiftRightUnsigned, ">>>="),
                 (AssignBitAnd, "&="),
                 (AssignBitXor, "^="),
                 (AssignBitOr, "|="),
@@ -375,6 +524,68 @@ mod test {
         );
     }
 
+    #[test]
+    fn operator_prefixes() {
+        assert_lex(
+            "
+                < <= << <<=
+                > >= >> >>= >>> >>>=
+                = == => =:
+                - -- -= ->
+                : :=
+            ",
+             &[
+                (OperatorLesser, "<"),
+                (OperatorLesserEquals, "<="),
+                (OperatorBitShiftLeft, "<<"),
+                (AssignBitShiftLeft, "<<="),
+                (OperatorGreater, ">"),
+                (OperatorGreaterEquals, ">="),
+                (OperatorBitShiftRight, ">>"),
+                (AssignBitShiftRight, ">>="),
+                (OperatorBitShiftRightUnsigned, ">>>"),
+                (AssignBitShiftRightUnsigned, ">>>="),
+                (Assign, "="),
+                (OperatorEquality, "=="),
+                (Arrow, "=>"),
+                (AssemblyAssign, "=:"),
+                (OperatorSubtraction, "-"),
+                (OperatorDecrement, "--"),
+                (AssignSubtraction, "-="),
+                (AssemblyArrow, "->"),
+                (Colon, ":"),
+                (AssemblyBind, ":="),
+            ][..]
+        );
+    }
+
+    #[test]
+    fn operators_maximal_munch() {
+        assert_lex(
+            "a>>>=b a>>=b a>=b a=>b a==b a!=b",
+             &[
+                (Identifier, "a"),
+                (AssignBitShiftRightUnsigned, ">>>="),
+                (Identifier, "b"),
+                (Identifier, "a"),
+                (AssignBitShiftRight, ">>="),
+                (Identifier, "b"),
+                (Identifier, "a"),
+                (OperatorGreaterEquals, ">="),
+                (Identifier, "b"),
+                (Identifier, "a"),
+                (Arrow, "=>"),
+                (Identifier, "b"),
+                (Identifier, "a"),
+                (OperatorEquality, "=="),
+                (Identifier, "b"),
+                (Identifier, "a"),
+                (OperatorInequality, "!="),
+                (Identifier, "b"),
+            ][..]
+        );
+    }
+
     #[test]
     fn types_easy() {
         assert_lex(
@@ -555,6 +766,25 @@ mod test {
         );
     }
 
+    #[test]
+    fn keyword_prefixes() {
+        assert_lex(
+            "
+                contractor hexagon int8x uint256a bytes32_ fixed8x1$ trueish _if
+            ",
+             &[
+                (Identifier, "contractor"),
+                (Identifier, "hexagon"),
+                (Identifier, "int8x"),
+                (Identifier, "uint256a"),
+                (Identifier, "bytes32_"),
+                (Identifier, "fixed8x1$"),
+                (Identifier, "trueish"),
+                (Identifier, "_if"),
+            ][..]
+        );
+    }
+
     #[test]
     fn not_real_types() {
         assert_lex(
@@ -595,4 +825,29 @@ mod test {
 
         assert_eq!(tokens, 1299);
     }
+
+    #[test]
+    fn token_classification() {
+        for token in &[DeclarationContract, DeclarationVar, KeywordReturns, KeywordWhile, ReservedWord] {
+            assert!(token.is_keyword(), "{:?} should be a keyword", token);
+        }
+
+        for token in &[TypeBool, TypeAddress, TypeBytes, TypeUint, TypeUfixed] {
+            assert!(token.is_elementary_type(), "{:?} should be an elementary type", token);
+        }
+
+        for token in &[LiteralTrue, LiteralHex, LiteralRational, LiteralString, LiteralHexString] {
+            assert!(token.is_literal(), "{:?} should be a literal", token);
+        }
+
+        for token in &[Identifier, IdentifierBuiltin, Semicolon, OperatorAddition, UnitEther] {
+            assert!(!token.is_keyword(), "{:?} should not be a keyword", token);
+            assert!(!token.is_elementary_type(), "{:?} should not be an elementary type", token);
+            assert!(!token.is_literal(), "{:?} should not be a literal", token);
+        }
+
+        assert!(!TypeUint.is_keyword());
+        assert!(!KeywordMapping.is_elementary_type());
+        assert!(!KeywordHex.is_literal());
+    }
 }
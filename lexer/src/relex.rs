@@ -0,0 +1,159 @@
+use std::ops::Range;
+
+use logos::Logos;
+use token::{Token, TypeSize};
+
+/// A token along with its location in the source.
+#[derive(Debug, PartialEq, Clone)]
+pub struct SpannedToken {
+    pub token: Token,
+    pub span: Range<usize>,
+
+    /// Size of the token, if it's a sized elementary type
+    pub extras: TypeSize,
+}
+
+/// Lex the entire source, collecting all tokens up to the end of program.
+pub fn tokenize(source: &str) -> Vec<SpannedToken> {
+    tokenize_with_capacity(source, 0)
+}
+
+/// Same as `tokenize`, but with room for `capacity` tokens reserved up front.
+pub fn tokenize_with_capacity(source: &str, capacity: usize) -> Vec<SpannedToken> {
+    let mut tokens = Vec::with_capacity(capacity);
+
+    lex_from(source, 0, |token| {
+        tokens.push(token);
+
+        true
+    });
+
+    tokens
+}
+
+/// Update `tokens` lexed from a previous version of the source, after the `edit`
+/// range in it has been replaced with `inserted` bytes to produce `source`.
+///
+/// Only the region affected by the edit is lexed again, tokens following it
+/// are reused and have their spans shifted by the difference in length.
+pub fn relex(tokens: &[SpannedToken], source: &str, edit: Range<usize>, inserted: usize) -> Vec<SpannedToken> {
+    let edit_end = edit.start + inserted;
+    let removed  = edit.end - edit.start;
+
+    // A token ending right at the edit might be extended by it, so it has to be lexed again.
+    let first   = tokens.iter().position(|token| token.span.end >= edit.start).unwrap_or(tokens.len());
+    let restart = match first {
+        0 => 0,
+        n => tokens[n - 1].span.end,
+    };
+
+    let mut spliced = tokens[..first].to_vec();
+    let mut old     = first;
+    let mut synced  = None;
+
+    lex_from(source, restart, |token| {
+        if token.span.start >= edit_end {
+            let old_start = token.span.start - inserted + removed;
+
+            while old < tokens.len() && tokens[old].span.start < old_start {
+                old += 1;
+            }
+
+            // Past the edit the source is unchanged, so once a token starts where
+            // one of the old ones did, the rest of the old tokens can be reused.
+            if old < tokens.len() && tokens[old].span.start == old_start && tokens[old].token == token.token {
+                synced = Some(old);
+
+                return false;
+            }
+        }
+
+        spliced.push(token);
+
+        true
+    });
+
+    if let Some(old) = synced {
+        spliced.extend(tokens[old..].iter().map(|token| SpannedToken {
+            token: token.token,
+            span: token.span.start - removed + inserted..token.span.end - removed + inserted,
+            extras: token.extras,
+        }));
+    }
+
+    spliced
+}
+
+fn lex_from<F>(source: &str, offset: usize, mut callback: F)
+where
+    F: FnMut(SpannedToken) -> bool,
+{
+    let mut lex = Token::lexer(&source[offset..]);
+
+    while lex.token != Token::EndOfProgram {
+        let range = lex.range();
+        let token = lex.token;
+
+        let proceed = callback(SpannedToken {
+            token,
+            span: offset + range.start..offset + range.end,
+            extras: lex.extras,
+        });
+
+        if !proceed || token == Token::UnexpectedEndOfProgram {
+            break;
+        }
+
+        lex.advance();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn assert_relex(source: &str, edit: Range<usize>, replacement: &str) {
+        let tokens = tokenize(source);
+        let edited = format!("{}{}{}", &source[..edit.start], replacement, &source[edit.end..]);
+
+        assert_eq!(relex(&tokens, &edited, edit, replacement.len()), tokenize(&edited));
+    }
+
+    #[test]
+    fn tokenize_spans() {
+        assert_eq!(tokenize(" foo = 10;"), vec![
+            SpannedToken { token: Token::Identifier, span: 1..4, extras: TypeSize(0, 0) },
+            SpannedToken { token: Token::Assign, span: 5..6, extras: TypeSize(0, 0) },
+            SpannedToken { token: Token::LiteralInteger, span: 7..9, extras: TypeSize(0, 0) },
+            SpannedToken { token: Token::Semicolon, span: 9..10, extras: TypeSize(0, 0) },
+        ]);
+    }
+
+    #[test]
+    fn tokenize_sizes() {
+        assert_eq!(tokenize("bytes20 foo"), vec![
+            SpannedToken { token: Token::TypeByte, span: 0..7, extras: TypeSize(20, 0) },
+            SpannedToken { token: Token::Identifier, span: 8..11, extras: TypeSize(0, 0) },
+        ]);
+    }
+
+    #[test]
+    fn relex_matches_full_lex() {
+        let source = "contract Foo { uint256 bar = 10; function baz() {} }";
+
+        // Replace a literal with a longer expression
+        assert_relex(source, 29..31, "bar + 1");
+
+        // Extend an identifier
+        assert_relex(source, 26..26, "ney");
+
+        // Shrink a type
+        assert_relex(source, 15..22, "int");
+
+        // Insert a comment eating a part of the source
+        assert_relex(source, 33..33, "/* ");
+
+        // Delete everything up to the end
+        assert_relex(source, 13..source.len(), "");
+    }
+}
@@ -4,16 +4,16 @@
 //!
 //!  ```text
 //!  EOF    ;      :      ,      .      (      )      {      }      [      ]      =>
-//!  IDENT  BLTIN  CONTR  LIB    IFACE  ENUM   STRUCT MODIF  EVENT  FUNCT  VAR    ANON
-//!  AS     ASM    BREAK  CONST  CONTIN DO     DELETE ELSE   EXTERN FOR    HEX    IF
-//!  INDEX  INTERN IMPORT IS     MAP    MEM    NEW    PAY    PULIC  PRAGMA PRIV   PURE
-//!  RET    RETNS  STORAG SUPER  THIS   THROW  USING  VIEW   WHILE  RESERV T_BOOL T_ADDR
-//!  T_STR  T_BYT  T_BYTS T_INT  T_UINT T_FIX  T_UFIX L_TRUE L_FALS L_HEX  L_INT  L_RAT
-//!  L_STR  E_ETH  E_FINN E_SZAB E_WEI  T_YEAR T_WEEK T_DAYS T_HOUR T_MIN  T_SEC  :=
-//!  =:     ++     --     !      ~      *      /      %      **     +      -      <<
-//!  >>     <      <=     >      >=     ==     !=     &      ^      |      &&     ||
-//!  ?      =      +=     -=     *=     /=     %=     <<=    >>=    &=     ^=     |=
-//!  ERRTOK ERREOF
+//!  IDENT  BLTIN  CONTR  LIB    IFACE  ENUM   STRUCT MODIF  EVENT  FUNCT  CONSTR VAR
+//!  ANON   AS     ASM    BREAK  CALLDT CATCH  CONST  CONTIN DO     DELETE ELSE   EXTERN
+//!  FOR    HEX    IF     INDEX  INTERN IMPORT IS     MAP    MEM    NEW    PAY    PULIC
+//!  PRAGMA PRIV   PURE   RET    RETNS  STORAG SUPER  THIS   THROW  TRY    TYPE   USING
+//!  VIEW   WHILE  RESERV T_BOOL T_ADDR T_STR  T_BYT  T_BYTS T_INT  T_UINT T_FIX  T_UFIX
+//!  L_TRUE L_FALS L_HEX  L_INT  L_RAT  L_STR  L_HEXS E_ETH  E_FINN E_SZAB E_WEI  T_YEAR
+//!  T_WEEK T_DAYS T_HOUR T_MIN  T_SEC  :=     =:     ->     ++     --     !      ~
+//!  *      /      %      **     +      -      <<     >>     >>>    <      <=     >
+//!  >=     ==     !=     &      ^      |      &&     ||     ?      =      +=     -=
+//!  *=     /=     %=     <<=    >>=    >>>=   &=     ^=     |=     ERRTOK ERREOF
 //!  ```
 //!
 
@@ -28,10 +28,16 @@ use logos::{Logos, Lexer, Extras, Source, Slice};
 /// - For `int64` this will be set to `(8, _)`
 /// - For `bytes20` this will be set to `(20, _)`
 /// - For 'ufixed128x40` this will be set to `(16, 40)`
-#[derive(Default, Clone, Copy)]
+#[derive(Debug, PartialEq, Default, Clone, Copy)]
 pub struct TypeSize(pub u8, pub u8);
 
-impl Extras for TypeSize {}
+impl Extras for TypeSize {
+    #[inline]
+    fn on_advance(&mut self) {
+        // Don't leak the size of a previous type onto the next token
+        *self = TypeSize::default();
+    }
+}
 
 #[derive(Debug, PartialEq, Clone, Copy, Logos)]
 #[extras = "TypeSize"]
@@ -72,6 +78,10 @@ pub enum Token {
     #[token = "=>"]
     Arrow,
 
+    // Identifiers share the state machine with keywords, which scans the rest
+    // of an identifier with a single table lookup per byte. Scanning them in
+    // a callback and looking keywords up afterwards is measurably slower, the
+    // state machine tells keywords apart for free while it scans.
     #[regex = "[a-zA-Z_$][a-zA-Z0-9_$]*"]
     Identifier,
 
@@ -104,6 +114,9 @@ pub enum Token {
     #[token = "function"]
     DeclarationFunction,
 
+    #[token = "constructor"]
+    DeclarationConstructor,
+
     #[token = "var"]
     DeclarationVar,
 
@@ -119,6 +132,12 @@ pub enum Token {
     #[token = "break"]
     KeywordBreak,
 
+    #[token = "calldata"]
+    KeywordCalldata,
+
+    #[token = "catch"]
+    KeywordCatch,
+
     #[token = "constant"]
     KeywordConstant,
 
@@ -140,7 +159,6 @@ pub enum Token {
     #[token = "for"]
     KeywordFor,
 
-    // FIXME: Should able to handle hex literals on lexer-level!
     #[token = "hex"]
     KeywordHex,
 
@@ -201,6 +219,12 @@ pub enum Token {
     #[token = "throw"]
     KeywordThrow,
 
+    #[token = "try"]
+    KeywordTry,
+
+    #[token = "type"]
+    KeywordType,
+
     #[token = "using"]
     KeywordUsing,
 
@@ -210,9 +234,9 @@ pub enum Token {
     #[token = "while"]
     KeywordWhile,
 
-    #[regex = "abstract|after|case|catch|default|final|in"]
+    #[regex = "abstract|after|case|default|final|in"]
     #[regex = "inline|let|match|null|of|relocatable|static"]
-    #[regex = "switch|try|type|typeof"]
+    #[regex = "switch|typeof"]
     ReservedWord,
 
     #[token = "bool"]
@@ -267,9 +291,10 @@ pub enum Token {
     LiteralHex,
 
     #[regex = "[0-9]+"]
+    #[callback = "fraction"]
     LiteralInteger,
 
-    #[regex = "[0-9]*\\.[0-9]+([eE][+-]?[0-9]+)?|[0-9]+[eE][+-]?[0-9]+"]
+    #[regex = "\\.[0-9]+([eE][+-]?[0-9]+)?|[0-9]+[eE][+-]?[0-9]+"]
     #[callback = "rational_to_integer"]
     LiteralRational,
 
@@ -277,6 +302,10 @@ pub enum Token {
     #[regex = "'([^'\\\\]|\\\\.)*'"]
     LiteralString,
 
+    #[regex = "hex\"([0-9a-fA-F][0-9a-fA-F](_?[0-9a-fA-F][0-9a-fA-F])*)?\""]
+    #[regex = "hex'([0-9a-fA-F][0-9a-fA-F](_?[0-9a-fA-F][0-9a-fA-F])*)?'"]
+    LiteralHexString,
+
     #[token = "ether"]
     UnitEther,
 
@@ -313,6 +342,9 @@ pub enum Token {
     #[token = "=:"]
     AssemblyAssign,
 
+    #[token = "->"]
+    AssemblyArrow,
+
     #[token = "++"]
     OperatorIncrement,
 
@@ -349,6 +381,9 @@ pub enum Token {
     #[token = ">>"]
     OperatorBitShiftRight,
 
+    #[token = ">>>"]
+    OperatorBitShiftRightUnsigned,
+
     #[token = "<"]
     OperatorLesser,
 
@@ -409,6 +444,9 @@ pub enum Token {
     #[token = ">>="]
     AssignBitShiftRight,
 
+    #[token = ">>>="]
+    AssignBitShiftRightUnsigned,
+
     #[token = "&="]
     AssignBitAnd,
 
@@ -418,35 +456,168 @@ pub enum Token {
     #[token = "|="]
     AssignBitOr,
 
-    #[regex = "//[^\n]*"]
+    #[token = "\n"]
+    #[token = "//"]
     #[token = "/*"]
-    #[callback = "ignore_comments"]
+    #[callback = "skip_trivia"]
     #[error]
     UnexpectedToken,
     UnexpectedEndOfProgram,
 }
 
-fn ignore_comments<'source, Src: Source<'source>>(lex: &mut Lexer<Token, Src>) {
+impl Token {
+    /// Declaration keywords, regular keywords and reserved words.
+    pub fn is_keyword(self) -> bool {
+        match self {
+            Token::DeclarationContract    |
+            Token::DeclarationLibrary     |
+            Token::DeclarationInterface   |
+            Token::DeclarationEnum        |
+            Token::DeclarationStruct      |
+            Token::DeclarationModifier    |
+            Token::DeclarationEvent       |
+            Token::DeclarationFunction    |
+            Token::DeclarationConstructor |
+            Token::DeclarationVar         |
+            Token::KeywordAnonymous       |
+            Token::KeywordAs              |
+            Token::KeywordAssembly        |
+            Token::KeywordBreak           |
+            Token::KeywordCalldata        |
+            Token::KeywordCatch           |
+            Token::KeywordConstant        |
+            Token::KeywordContinue        |
+            Token::KeywordDo              |
+            Token::KeywordDelete          |
+            Token::KeywordElse            |
+            Token::KeywordExternal        |
+            Token::KeywordFor             |
+            Token::KeywordHex             |
+            Token::KeywordIf              |
+            Token::KeywordIndexed         |
+            Token::KeywordInternal        |
+            Token::KeywordImport          |
+            Token::KeywordIs              |
+            Token::KeywordMapping         |
+            Token::KeywordMemory          |
+            Token::KeywordNew             |
+            Token::KeywordPayable         |
+            Token::KeywordPublic          |
+            Token::KeywordPragma          |
+            Token::KeywordPrivate         |
+            Token::KeywordPure            |
+            Token::KeywordReturn          |
+            Token::KeywordReturns         |
+            Token::KeywordStorage         |
+            Token::KeywordSuper           |
+            Token::KeywordThis            |
+            Token::KeywordThrow           |
+            Token::KeywordTry             |
+            Token::KeywordType            |
+            Token::KeywordUsing           |
+            Token::KeywordView            |
+            Token::KeywordWhile           |
+            Token::ReservedWord           => true,
+            _                             => false,
+        }
+    }
+
+    /// Elementary type names, such as `address` or `uint256`.
+    pub fn is_elementary_type(self) -> bool {
+        match self {
+            Token::TypeBool    |
+            Token::TypeAddress |
+            Token::TypeString  |
+            Token::TypeByte    |
+            Token::TypeBytes   |
+            Token::TypeInt     |
+            Token::TypeUint    |
+            Token::TypeIntN    |
+            Token::TypeUintN   |
+            Token::TypeFixed   |
+            Token::TypeUfixed  => true,
+            _                  => false,
+        }
+    }
+
+    /// Boolean, number and string literals.
+    pub fn is_literal(self) -> bool {
+        match self {
+            Token::LiteralTrue      |
+            Token::LiteralFalse     |
+            Token::LiteralHex       |
+            Token::LiteralInteger   |
+            Token::LiteralRational  |
+            Token::LiteralString    |
+            Token::LiteralHexString => true,
+            _                       => false,
+        }
+    }
+}
+
+/// Line breaks and comments are matched as tokens only to end up here, where
+/// the indentation and comments up to the next actual token are skipped in
+/// one go, instead of the lexer going through them a byte at a time. Lone
+/// spaces between tokens are still cheaper to leave to the lexer itself.
+fn skip_trivia<'source, Src: Source<'source>>(lex: &mut Lexer<Token, Src>) {
     use logos::internal::LexerInternal;
 
-    if lex.slice().as_bytes() == b"/*" {
-        loop {
+    match lex.slice().as_bytes() {
+        b"\n" => skip_indentation(lex),
+        b"//" => skip_line(lex),
+        b"/*" => loop {
             match lex.read() {
                 0    => return lex.token = Token::UnexpectedEndOfProgram,
-                b'*' => {
-                    if lex.next() == b'/' {
-                        lex.bump();
-                        break;
-                    }
+                b'*' if peek(lex, 1) == b'/' => {
+                    lex.bump();
+                    lex.bump();
+                    break;
                 },
                 _ => lex.bump(),
             }
+        },
+        _ => {},
+    }
+
+    loop {
+        match lex.read() {
+            b'\n' => {
+                lex.bump();
+                skip_indentation(lex);
+            },
+            b' ' | b'\t' | b'\r' => lex.bump(),
+            b'/' if peek(lex, 1) == b'/' => skip_line(lex),
+            // Block comments start over as their own token, so that an
+            // unterminated one is reported from where it starts
+            _ => break,
         }
     }
 
     lex.advance();
 }
 
+/// Indentation is mostly made of spaces, skip them eight at a time.
+fn skip_indentation<'source, Src: Source<'source>>(lex: &mut Lexer<Token, Src>) {
+    use logos::internal::LexerInternal;
+
+    while lex.source.slice(lex.range().end..lex.range().end + 8).map(|slice| slice.as_bytes() == b"        ") == Some(true) {
+        for _ in 0..8 {
+            lex.bump();
+        }
+    }
+}
+
+fn skip_line<'source, Src: Source<'source>>(lex: &mut Lexer<Token, Src>) {
+    use logos::internal::LexerInternal;
+
+    loop {
+        match lex.read() {
+            0 | b'\n' => break,
+            _ => lex.bump(),
+        }
+    }
+}
+
 fn validate_bytes<'source, Src: Source<'source>>(lex: &mut Lexer<Token, Src>) {
     let slice = lex.slice().as_bytes();
 
@@ -500,7 +671,7 @@ fn validate_fixed<'source, Src: Source<'source>>(lex: &mut Lexer<Token, Src>) {
 
     let mut iter = slice[cutoff..].iter();
 
-    while let Some(&byte) = iter.next() {
+    for &byte in iter.by_ref() {
         if byte == b'x' {
             break;
         }
@@ -520,6 +691,58 @@ fn validate_fixed<'source, Src: Source<'source>>(lex: &mut Lexer<Token, Src>) {
     }
 }
 
+/// The fractional part of a number is lexed here rather than in the regex,
+/// so that a dot followed by an identifier can be left alone: `5.` is the
+/// same as `5`, but `5.foo` and `5.e3` are member accesses.
+fn fraction<'source, Src: Source<'source>>(lex: &mut Lexer<Token, Src>) {
+    use logos::internal::LexerInternal;
+
+    if lex.read() != b'.' {
+        return;
+    }
+
+    match peek(lex, 1) {
+        b'a'..=b'z' | b'A'..=b'Z' | b'_' | b'$' => return,
+        b'0'..=b'9' => {},
+        _ => return lex.bump(),
+    }
+
+    lex.bump();
+
+    while let b'0'..=b'9' = lex.read() {
+        lex.bump();
+    }
+
+    if let b'e' | b'E' = lex.read() {
+        let sign = match peek(lex, 1) {
+            b'+' | b'-' => 1,
+            _           => 0,
+        };
+
+        if let b'0'..=b'9' = peek(lex, 1 + sign) {
+            for _ in 0..1 + sign {
+                lex.bump();
+            }
+
+            while let b'0'..=b'9' = lex.next() {}
+        }
+    }
+
+    lex.token = Token::LiteralRational;
+
+    rational_to_integer(lex);
+}
+
+/// Read a byte `ahead` of the current position, without consuming anything.
+fn peek<'source, Src: Source<'source>>(lex: &Lexer<Token, Src>, ahead: usize) -> u8 {
+    let offset = lex.range().end + ahead;
+
+    match offset <= lex.source.len() {
+        true  => unsafe { lex.source.read(offset) },
+        false => 0,
+    }
+}
+
 fn rational_to_integer<'source, Src: Source<'source>>(lex: &mut Lexer<Token, Src>) {
     let mut floating = 0i32;
     let mut iter = lex.slice().as_bytes().iter();
@@ -532,7 +755,7 @@ fn rational_to_integer<'source, Src: Source<'source>>(lex: &mut Lexer<Token, Src
                 floating = 0;
                 let mut zeroes = 0;
 
-                while let Some(&byte) = iter.next() {
+                for &byte in iter.by_ref() {
                     match byte {
                         b'e' | b'E' => break 'outer,
                         b'0' => zeroes += 1,
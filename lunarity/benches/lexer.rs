@@ -0,0 +1,86 @@
+#![feature(test)]
+
+extern crate test;
+extern crate toolshed;
+extern crate lunarity;
+
+use lunarity::lexer::{Lexer, Token};
+
+use test::Bencher;
+
+/// A large contract made up mostly of identifiers of varying length.
+fn identifiers_source() -> String {
+    let mut source = String::from("contract Identifiers {\n");
+
+    for i in 0..1000 {
+        source.push_str(&format!(
+            "    uint256 balance_of_{} = _totalSupply{} * rate$ + previous_balance_{}_x;\n",
+            i, i, i
+        ));
+    }
+
+    source.push_str("}\n");
+    source
+}
+
+/// Deeply nested blocks, most of the bytes are indentation.
+fn indented_source() -> String {
+    let mut source = String::from("contract Indented {\n    function f() {\n");
+
+    for depth in 0..200 {
+        source.push_str(&" ".repeat(8 + depth * 4));
+        source.push_str("{\n");
+    }
+
+    for depth in (0..200).rev() {
+        source.push_str(&"\t".repeat(2 + depth));
+        source.push_str("}\n");
+    }
+
+    source.push_str("    }\n}\n");
+    source
+}
+
+/// Generated code with very long hex literals and identifiers.
+fn long_tokens_source() -> String {
+    let mut source = String::from("contract Long {\n");
+
+    for i in 0..10 {
+        source.push_str(&format!(
+            "    uint256 {}{} = 0x{};\n",
+            "x".repeat(10_000), i, "f".repeat(10_000)
+        ));
+    }
+
+    source.push_str("}\n");
+    source
+}
+
+fn tokenize_source(b: &mut Bencher, source: &str) {
+    let arena = toolshed::Arena::new();
+    let nts = arena.alloc_nul_term_str(source);
+    b.bytes = source.len() as u64;
+
+    b.iter(|| {
+        let mut lexer = Lexer::new(nts);
+
+        while lexer.token != Token::EndOfProgram {
+            lexer.advance()
+        }
+    });
+}
+
+#[bench]
+fn tokenize_identifiers(b: &mut Bencher) {
+    tokenize_source(b, &identifiers_source());
+}
+
+#[bench]
+fn tokenize_whitespace(b: &mut Bencher) {
+    tokenize_source(b, &indented_source());
+}
+
+#[bench]
+fn tokenize_long_tokens(b: &mut Bencher) {
+    tokenize_source(b, &long_tokens_source());
+}
@@ -0,0 +1,51 @@
+#![feature(test)]
+
+extern crate test;
+extern crate lunarity;
+extern crate lunarity_parser;
+
+use lunarity::parse;
+use lunarity_parser::{Parser, parse_using};
+
+use test::{Bencher, black_box};
+
+static SOURCE: &'static str = include_str!("./second-price-auction.sol");
+
+#[bench]
+fn parse_many_contracts(b: &mut Bencher) {
+    let source = SOURCE.repeat(50);
+
+    b.bytes = source.len() as u64;
+
+    b.iter(|| {
+        let program = parse(&source);
+
+        black_box(program.unwrap())
+    });
+}
+
+#[bench]
+fn parse_many_contracts_buffered(b: &mut Bencher) {
+    let source = SOURCE.repeat(50);
+
+    b.bytes = source.len() as u64;
+
+    b.iter(|| {
+        let program = parse_using(&source, |source, arena| Parser::with_capacity(source, arena, 0));
+
+        black_box(program.unwrap())
+    });
+}
+
+#[bench]
+fn parse_many_contracts_buffered_presized(b: &mut Bencher) {
+    let source = SOURCE.repeat(50);
+
+    b.bytes = source.len() as u64;
+
+    b.iter(|| {
+        let program = parse_using(&source, |source, arena| Parser::buffered(source, arena));
+
+        black_box(program.unwrap())
+    });
+}
@@ -2,4 +2,4 @@ pub extern crate lunarity_ast as ast;
 pub extern crate lunarity_lexer as lexer;
 
 extern crate lunarity_parser;
-pub use lunarity_parser::parse;
+pub use lunarity_parser::{parse, parse_summary};
@@ -25,40 +25,215 @@ impl<'ast> Parser<'ast> {
 
     fn assembly_item(&mut self) -> Option<AssemblyItemNode<'ast>> {
         match self.lexer.token {
-            Token::BraceOpen  => self.inline_assembly_block(),
-            Token::Identifier => self.assembly_identifier(),
-            _                 => None,
+            Token::BraceOpen           => self.inline_assembly_block(),
+            Token::Identifier if self.lexer.slice() == "leave" => self.node_at_token(AssemblyItem::Leave),
+            Token::Identifier          => self.assembly_identifier(),
+            Token::IdentifierBuiltin   => self.assembly_identifier(),
+            Token::LiteralInteger      => self.node_from_slice(|slice| Primitive::IntegerNumber(slice, NumberUnit::None)),
+            Token::LiteralHex          => self.node_from_slice(Primitive::HexNumber),
+            Token::LiteralString       => self.node_from_slice(AssemblyItem::StringLiteral),
+            Token::LiteralHexString    => self.node_from_slice(AssemblyItem::HexLiteral),
+            Token::LiteralTrue         => self.node_at_token(AssemblyItem::BoolLiteral(true)),
+            Token::LiteralFalse        => self.node_at_token(AssemblyItem::BoolLiteral(false)),
+            Token::KeywordBreak        => self.node_at_token(AssemblyItem::Break),
+            Token::KeywordContinue     => self.node_at_token(AssemblyItem::Continue),
+            Token::KeywordIf           => self.assembly_if(),
+            Token::KeywordFor          => self.assembly_for_loop(),
+            Token::DeclarationFunction => self.assembly_function_definition(),
+            Token::ReservedWord        => match self.lexer.slice() {
+                "let"    => self.assembly_local_binding(),
+                "switch" => self.assembly_switch(),
+                _        => None,
+            },
+            _                          => None,
         }
     }
 
-    fn assembly_identifier(&mut self) -> Option<AssemblyItemNode<'ast>> {
-        let (start, end) = self.loc();
-        let identifier = self.lexer.slice();
+    fn assembly_local_binding(&mut self) -> Option<AssemblyItemNode<'ast>> {
+        let start = self.start_then_advance();
+        let id    = self.expect_str_node(Token::Identifier);
 
-        self.lexer.advance();
+        let init;
+        let end;
 
         if self.allow(Token::AssemblyBind) {
-            let id   = self.node_at(start, end, identifier);
-            let init = expect!(self, self.functional_assembly_expression());
+            let item = expect!(self, self.assembly_item());
+
+            init = Some(item);
+            end  = item.end;
+        } else {
+            init = None;
+            end  = id.end;
+        }
+
+        self.node_at(start, end, AssemblyLocalBinding {
+            id,
+            init,
+        })
+    }
+
+    fn nested_assembly_block(&mut self) -> Option<InlineAssemblyBlockNode<'ast>> {
+        if self.lexer.token != Token::BraceOpen {
+            self.error();
+
+            return None;
+        }
+
+        self.inline_assembly_block()
+    }
+
+    fn assembly_if(&mut self) -> Option<AssemblyItemNode<'ast>> {
+        let start = self.start_then_advance();
+        let test  = expect!(self, self.assembly_item());
+        let body  = self.nested_assembly_block()?;
+
+        self.node_at(start, body.end, AssemblyIf {
+            test,
+            body,
+        })
+    }
+
+    fn assembly_for_loop(&mut self) -> Option<AssemblyItemNode<'ast>> {
+        let start  = self.start_then_advance();
+        let init   = self.nested_assembly_block()?;
+        let test   = expect!(self, self.assembly_item());
+        let update = self.nested_assembly_block()?;
+        let body   = self.nested_assembly_block()?;
+
+        self.node_at(start, body.end, AssemblyForLoop {
+            init,
+            test,
+            update,
+            body,
+        })
+    }
+
+    fn assembly_switch(&mut self) -> Option<AssemblyItemNode<'ast>> {
+        let start        = self.start_then_advance();
+        let discriminant = expect!(self, self.assembly_item());
+        let cases        = GrowableList::new();
+
+        let mut end = discriminant.end;
+
+        while self.lexer.token == Token::ReservedWord {
+            let has_test = match self.lexer.slice() {
+                "case"    => true,
+                "default" => false,
+                _         => break,
+            };
 
-            return self.node_at(start, init.end, AssemblyAssignment {
-                id,
-                init,
+            let start = self.start_then_advance();
+
+            let test = if has_test {
+                Some(expect!(self, self.assembly_item()))
+            } else {
+                None
+            };
+
+            let body = self.nested_assembly_block()?;
+
+            end = body.end;
+
+            let case = self.node_at(start, end, AssemblyCase {
+                test,
+                body,
             });
+
+            cases.push(self.arena, case);
         }
 
-        self.node_at(start, end, identifier)
+        self.node_at(start, end, AssemblySwitch {
+            discriminant,
+            cases: cases.as_list(),
+        })
     }
 
-    fn functional_assembly_expression(&mut self) -> Option<FunctionalAssemblyExpressionNode<'ast>> {
-        let id = self.expect_str_node(Token::Identifier);
+    fn assembly_function_definition(&mut self) -> Option<AssemblyItemNode<'ast>> {
+        let start = self.start_then_advance();
+        let name  = self.expect_str_node(Token::Identifier);
 
         self.expect(Token::ParenOpen);
 
+        let params = GrowableList::new();
+
+        if let Some(param) = self.allow_str_node(Token::Identifier) {
+            params.push(self.arena, param);
+
+            while self.allow(Token::Comma) {
+                params.push(self.arena, self.expect_str_node(Token::Identifier));
+            }
+        }
+
+        self.expect(Token::ParenClose);
+
+        let returns = GrowableList::new();
+
+        if self.allow(Token::AssemblyArrow) {
+            returns.push(self.arena, self.expect_str_node(Token::Identifier));
+
+            while self.allow(Token::Comma) {
+                returns.push(self.arena, self.expect_str_node(Token::Identifier));
+            }
+        }
+
+        let body = self.nested_assembly_block()?;
+
+        self.node_at(start, body.end, AssemblyFunctionDefinition {
+            name,
+            params: params.as_list(),
+            returns: returns.as_list(),
+            body,
+        })
+    }
+
+    fn assembly_identifier(&mut self) -> Option<AssemblyItemNode<'ast>> {
+        let (start, end) = self.loc();
+        let identifier = self.lexer.slice();
+
+        self.lexer.advance();
+
+        let id = self.node_at(start, end, identifier);
+
+        match self.lexer.token {
+            Token::AssemblyBind => {
+                self.lexer.advance();
+
+                let init = expect!(self, self.assembly_item());
+
+                self.node_at(start, init.end, AssemblyAssignment {
+                    id,
+                    init,
+                })
+            },
+            Token::Accessor => {
+                self.lexer.advance();
+
+                let member = self.expect_str_node(Token::Identifier);
+
+                self.node_at(start, member.end, AssemblyMemberAccess {
+                    object: id,
+                    member,
+                })
+            },
+            Token::ParenOpen => self.functional_assembly_expression(id),
+            _                => self.node_at(start, end, identifier),
+        }
+    }
+
+    fn functional_assembly_expression(&mut self, id: IdentifierNode<'ast>) -> Option<AssemblyItemNode<'ast>> {
+        self.expect(Token::ParenOpen);
+
         let arguments = GrowableList::new();
 
-        while let Some(item) = self.assembly_item() {
+        if let Some(item) = self.assembly_item() {
             arguments.push(self.arena, item);
+
+            while self.allow(Token::Comma) {
+                match self.assembly_item() {
+                    Some(item) => arguments.push(self.arena, item),
+                    None       => self.error(),
+                }
+            }
         }
 
         let end = self.expect_end(Token::ParenClose);
@@ -69,3 +244,631 @@ impl<'ast> Parser<'ast> {
         })
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use mock::{Mock, assert_units};
+
+    #[test]
+    fn assembly_local_binding() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                function() {
+                    assembly {
+                        let x := 0
+                        let y
+                        y := add(x, 0x20)
+                    }
+                }
+            }
+
+        "#, [
+            m.node(14, 249, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 235, FunctionDefinition {
+                        name: None,
+                        params: NodeList::empty(),
+                        visibility: None,
+                        mutability: None,
+                        is_virtual: None,
+                        overrides: None,
+                        modifiers: NodeList::empty(),
+                        returns: NodeList::empty(),
+                        block: m.node(56, 235, Block {
+                            body: m.list([
+                                m.node(78, 217, InlineAssemblyStatement {
+                                    string: None,
+                                    flags: NodeList::empty(),
+                                    block: m.node(87, 217, InlineAssemblyBlock {
+                                        items: m.list([
+                                            m.node(113, 123, AssemblyLocalBinding {
+                                                id: m.node(117, 118, "x"),
+                                                init: m.node(122, 123, Primitive::IntegerNumber("0", NumberUnit::None)),
+                                            }),
+                                            m.node(148, 153, AssemblyLocalBinding {
+                                                id: m.node(152, 153, "y"),
+                                                init: None,
+                                            }),
+                                            m.node(178, 195, AssemblyAssignment {
+                                                id: m.node(178, 179, "y"),
+                                                init: m.node(183, 195, FunctionalAssemblyExpression {
+                                                    id: m.node(183, 186, "add"),
+                                                    arguments: m.list([
+                                                        m.node(187, 188, "x"),
+                                                        m.node(190, 194, Primitive::HexNumber("0x20")),
+                                                    ]),
+                                                }),
+                                            }),
+                                        ]),
+                                    }),
+                                }),
+                            ]),
+                        }),
+                    }),
+                ]),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn functional_assembly_expression() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                function() {
+                    assembly {
+                        mstore(0x40, add(x, 1))
+                    }
+                }
+            }
+
+        "#, [
+            m.node(14, 190, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 176, FunctionDefinition {
+                        name: None,
+                        params: NodeList::empty(),
+                        visibility: None,
+                        mutability: None,
+                        is_virtual: None,
+                        overrides: None,
+                        modifiers: NodeList::empty(),
+                        returns: NodeList::empty(),
+                        block: m.node(56, 176, Block {
+                            body: m.list([
+                                m.node(78, 158, InlineAssemblyStatement {
+                                    string: None,
+                                    flags: NodeList::empty(),
+                                    block: m.node(87, 158, InlineAssemblyBlock {
+                                        items: m.list([
+                                            m.node(113, 136, FunctionalAssemblyExpression {
+                                                id: m.node(113, 119, "mstore"),
+                                                arguments: m.list([
+                                                    m.node(120, 124, Primitive::HexNumber("0x40")),
+                                                    m.node(126, 135, FunctionalAssemblyExpression {
+                                                        id: m.node(126, 129, "add"),
+                                                        arguments: m.list([
+                                                            m.node(130, 131, "x"),
+                                                            m.node(133, 134, Primitive::IntegerNumber("1", NumberUnit::None)),
+                                                        ]),
+                                                    }),
+                                                ]),
+                                            }),
+                                        ]),
+                                    }),
+                                }),
+                            ]),
+                        }),
+                    }),
+                ]),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn assembly_for_loop() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                function() {
+                    assembly {
+                        for { let i := 0 } lt(i, n) { i := add(i, 1) } {
+                            if eq(i, 2) { x }
+                        }
+                    }
+                }
+            }
+
+        "#, [
+            m.node(14, 287, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 273, FunctionDefinition {
+                        name: None,
+                        params: NodeList::empty(),
+                        visibility: None,
+                        mutability: None,
+                        is_virtual: None,
+                        overrides: None,
+                        modifiers: NodeList::empty(),
+                        returns: NodeList::empty(),
+                        block: m.node(56, 273, Block {
+                            body: m.list([
+                                m.node(78, 255, InlineAssemblyStatement {
+                                    string: None,
+                                    flags: NodeList::empty(),
+                                    block: m.node(87, 255, InlineAssemblyBlock {
+                                        items: m.list([
+                                            m.node(113, 233, AssemblyForLoop {
+                                                init: m.node(117, 131, InlineAssemblyBlock {
+                                                    items: m.list([
+                                                        m.node(119, 129, AssemblyLocalBinding {
+                                                            id: m.node(123, 124, "i"),
+                                                            init: m.node(128, 129, Primitive::IntegerNumber("0", NumberUnit::None)),
+                                                        }),
+                                                    ]),
+                                                }),
+                                                test: m.node(132, 140, FunctionalAssemblyExpression {
+                                                    id: m.node(132, 134, "lt"),
+                                                    arguments: m.list([
+                                                        m.node(135, 136, "i"),
+                                                        m.node(138, 139, "n"),
+                                                    ]),
+                                                }),
+                                                update: m.node(141, 159, InlineAssemblyBlock {
+                                                    items: m.list([
+                                                        m.node(143, 157, AssemblyAssignment {
+                                                            id: m.node(143, 144, "i"),
+                                                            init: m.node(148, 157, FunctionalAssemblyExpression {
+                                                                id: m.node(148, 151, "add"),
+                                                                arguments: m.list([
+                                                                    m.node(152, 153, "i"),
+                                                                    m.node(155, 156, Primitive::IntegerNumber("1", NumberUnit::None)),
+                                                                ]),
+                                                            }),
+                                                        }),
+                                                    ]),
+                                                }),
+                                                body: m.node(160, 233, InlineAssemblyBlock {
+                                                    items: m.list([
+                                                        m.node(190, 207, AssemblyIf {
+                                                            test: m.node(193, 201, FunctionalAssemblyExpression {
+                                                                id: m.node(193, 195, "eq"),
+                                                                arguments: m.list([
+                                                                    m.node(196, 197, "i"),
+                                                                    m.node(199, 200, Primitive::IntegerNumber("2", NumberUnit::None)),
+                                                                ]),
+                                                            }),
+                                                            body: m.node(202, 207, InlineAssemblyBlock {
+                                                                items: m.list([
+                                                                    m.node(204, 205, "x"),
+                                                                ]),
+                                                            }),
+                                                        }),
+                                                    ]),
+                                                }),
+                                            }),
+                                        ]),
+                                    }),
+                                }),
+                            ]),
+                        }),
+                    }),
+                ]),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn assembly_switch() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                function() {
+                    assembly {
+                        switch x
+                        case 0 { y := 1 }
+                        default { y := 2 }
+                    }
+                }
+            }
+
+        "#, [
+            m.node(14, 260, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 246, FunctionDefinition {
+                        name: None,
+                        params: NodeList::empty(),
+                        visibility: None,
+                        mutability: None,
+                        is_virtual: None,
+                        overrides: None,
+                        modifiers: NodeList::empty(),
+                        returns: NodeList::empty(),
+                        block: m.node(56, 246, Block {
+                            body: m.list([
+                                m.node(78, 228, InlineAssemblyStatement {
+                                    string: None,
+                                    flags: NodeList::empty(),
+                                    block: m.node(87, 228, InlineAssemblyBlock {
+                                        items: m.list([
+                                            m.node(113, 206, AssemblySwitch {
+                                                discriminant: m.node(120, 121, "x"),
+                                                cases: m.list([
+                                                    m.node(146, 163, AssemblyCase {
+                                                        test: m.node(151, 152, Primitive::IntegerNumber("0", NumberUnit::None)),
+                                                        body: m.node(153, 163, InlineAssemblyBlock {
+                                                            items: m.list([
+                                                                m.node(155, 161, AssemblyAssignment {
+                                                                    id: m.node(155, 156, "y"),
+                                                                    init: m.node(160, 161, Primitive::IntegerNumber("1", NumberUnit::None)),
+                                                                }),
+                                                            ]),
+                                                        }),
+                                                    }),
+                                                    m.node(188, 206, AssemblyCase {
+                                                        test: None,
+                                                        body: m.node(196, 206, InlineAssemblyBlock {
+                                                            items: m.list([
+                                                                m.node(198, 204, AssemblyAssignment {
+                                                                    id: m.node(198, 199, "y"),
+                                                                    init: m.node(203, 204, Primitive::IntegerNumber("2", NumberUnit::None)),
+                                                                }),
+                                                            ]),
+                                                        }),
+                                                    }),
+                                                ]),
+                                            }),
+                                        ]),
+                                    }),
+                                }),
+                            ]),
+                        }),
+                    }),
+                ]),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn assembly_function_definition() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                function() {
+                    assembly {
+                        function add3(a, b, c) -> r {
+                            r := add(add(a, b), c)
+                        }
+                    }
+                }
+            }
+
+        "#, [
+            m.node(14, 273, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 259, FunctionDefinition {
+                        name: None,
+                        params: NodeList::empty(),
+                        visibility: None,
+                        mutability: None,
+                        is_virtual: None,
+                        overrides: None,
+                        modifiers: NodeList::empty(),
+                        returns: NodeList::empty(),
+                        block: m.node(56, 259, Block {
+                            body: m.list([
+                                m.node(78, 241, InlineAssemblyStatement {
+                                    string: None,
+                                    flags: NodeList::empty(),
+                                    block: m.node(87, 241, InlineAssemblyBlock {
+                                        items: m.list([
+                                            m.node(113, 219, AssemblyFunctionDefinition {
+                                                name: m.node(122, 126, "add3"),
+                                                params: m.list([
+                                                    m.node(127, 128, "a"),
+                                                    m.node(130, 131, "b"),
+                                                    m.node(133, 134, "c"),
+                                                ]),
+                                                returns: m.list([
+                                                    m.node(139, 140, "r"),
+                                                ]),
+                                                body: m.node(141, 219, InlineAssemblyBlock {
+                                                    items: m.list([
+                                                        m.node(171, 193, AssemblyAssignment {
+                                                            id: m.node(171, 172, "r"),
+                                                            init: m.node(176, 193, FunctionalAssemblyExpression {
+                                                                id: m.node(176, 179, "add"),
+                                                                arguments: m.list([
+                                                                    m.node(180, 189, FunctionalAssemblyExpression {
+                                                                        id: m.node(180, 183, "add"),
+                                                                        arguments: m.list([
+                                                                            m.node(184, 185, "a"),
+                                                                            m.node(187, 188, "b"),
+                                                                        ]),
+                                                                    }),
+                                                                    m.node(191, 192, "c"),
+                                                                ]),
+                                                            }),
+                                                        }),
+                                                    ]),
+                                                }),
+                                            }),
+                                        ]),
+                                    }),
+                                }),
+                            ]),
+                        }),
+                    }),
+                ]),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn assembly_member_access() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                function() {
+                    assembly {
+                        sstore(x.slot, 1)
+                    }
+                }
+            }
+
+        "#, [
+            m.node(14, 184, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 170, FunctionDefinition {
+                        name: None,
+                        params: NodeList::empty(),
+                        visibility: None,
+                        mutability: None,
+                        is_virtual: None,
+                        overrides: None,
+                        modifiers: NodeList::empty(),
+                        returns: NodeList::empty(),
+                        block: m.node(56, 170, Block {
+                            body: m.list([
+                                m.node(78, 152, InlineAssemblyStatement {
+                                    string: None,
+                                    flags: NodeList::empty(),
+                                    block: m.node(87, 152, InlineAssemblyBlock {
+                                        items: m.list([
+                                            m.node(113, 130, FunctionalAssemblyExpression {
+                                                id: m.node(113, 119, "sstore"),
+                                                arguments: m.list([
+                                                    m.node(120, 126, AssemblyMemberAccess {
+                                                        object: m.node(120, 121, "x"),
+                                                        member: m.node(122, 126, "slot"),
+                                                    }),
+                                                    m.node(128, 129, Primitive::IntegerNumber("1", NumberUnit::None)),
+                                                ]),
+                                            }),
+                                        ]),
+                                    }),
+                                }),
+                            ]),
+                        }),
+                    }),
+                ]),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn assembly_dialect_and_flags() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                function() {
+                    assembly "evmasm" { }
+                    assembly ("memory-safe") { }
+                }
+            }
+
+        "#, [
+            m.node(14, 180, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 166, FunctionDefinition {
+                        name: None,
+                        params: NodeList::empty(),
+                        visibility: None,
+                        mutability: None,
+                        is_virtual: None,
+                        overrides: None,
+                        modifiers: NodeList::empty(),
+                        returns: NodeList::empty(),
+                        block: m.node(56, 166, Block {
+                            body: m.list([
+                                m.node(78, 99, InlineAssemblyStatement {
+                                    string: m.node(87, 95, "\"evmasm\""),
+                                    flags: NodeList::empty(),
+                                    block: m.node(96, 99, InlineAssemblyBlock {
+                                        items: NodeList::empty(),
+                                    }),
+                                }),
+                                m.node(120, 148, InlineAssemblyStatement {
+                                    string: None,
+                                    flags: m.list([
+                                        m.node(130, 143, "\"memory-safe\""),
+                                    ]),
+                                    block: m.node(145, 148, InlineAssemblyBlock {
+                                        items: NodeList::empty(),
+                                    }),
+                                }),
+                            ]),
+                        }),
+                    }),
+                ]),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn assembly_control_flow_and_literals() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                function() {
+                    assembly {
+                        for { let i := 0 } lt(i, 10) { i := add(i, 1) } {
+                            if eq(i, 5) { break }
+                            continue
+                        }
+                        function f() -> r {
+                            r := true
+                            leave
+                        }
+                        let s := "foo"
+                        let h := hex"c0ffee"
+                    }
+                }
+            }
+
+        "#, [
+            m.node(14, 555, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 541, FunctionDefinition {
+                        name: None,
+                        params: NodeList::empty(),
+                        visibility: None,
+                        mutability: None,
+                        is_virtual: None,
+                        overrides: None,
+                        modifiers: NodeList::empty(),
+                        returns: NodeList::empty(),
+                        block: m.node(56, 541, Block {
+                            body: m.list([
+                                m.node(78, 523, InlineAssemblyStatement {
+                                    string: None,
+                                    flags: NodeList::empty(),
+                                    block: m.node(87, 523, InlineAssemblyBlock {
+                                        items: m.list([
+                                            m.node(113, 275, AssemblyForLoop {
+                                                init: m.node(117, 131, InlineAssemblyBlock {
+                                                    items: m.list([
+                                                        m.node(119, 129, AssemblyLocalBinding {
+                                                            id: m.node(123, 124, "i"),
+                                                            init: m.node(128, 129, Primitive::IntegerNumber("0", NumberUnit::None)),
+                                                        }),
+                                                    ]),
+                                                }),
+                                                test: m.node(132, 141, FunctionalAssemblyExpression {
+                                                    id: m.node(132, 134, "lt"),
+                                                    arguments: m.list([
+                                                        m.node(135, 136, "i"),
+                                                        m.node(138, 140, Primitive::IntegerNumber("10", NumberUnit::None)),
+                                                    ]),
+                                                }),
+                                                update: m.node(142, 160, InlineAssemblyBlock {
+                                                    items: m.list([
+                                                        m.node(144, 158, AssemblyAssignment {
+                                                            id: m.node(144, 145, "i"),
+                                                            init: m.node(149, 158, FunctionalAssemblyExpression {
+                                                                id: m.node(149, 152, "add"),
+                                                                arguments: m.list([
+                                                                    m.node(153, 154, "i"),
+                                                                    m.node(156, 157, Primitive::IntegerNumber("1", NumberUnit::None)),
+                                                                ]),
+                                                            }),
+                                                        }),
+                                                    ]),
+                                                }),
+                                                body: m.node(161, 275, InlineAssemblyBlock {
+                                                    items: m.list([
+                                                        m.node(191, 212, AssemblyIf {
+                                                            test: m.node(194, 202, FunctionalAssemblyExpression {
+                                                                id: m.node(194, 196, "eq"),
+                                                                arguments: m.list([
+                                                                    m.node(197, 198, "i"),
+                                                                    m.node(200, 201, Primitive::IntegerNumber("5", NumberUnit::None)),
+                                                                ]),
+                                                            }),
+                                                            body: m.node(203, 212, InlineAssemblyBlock {
+                                                                items: m.list([
+                                                                    m.node(205, 210, AssemblyItem::Break),
+                                                                ]),
+                                                            }),
+                                                        }),
+                                                        m.node(241, 249, AssemblyItem::Continue),
+                                                    ]),
+                                                }),
+                                            }),
+                                            m.node(300, 417, AssemblyFunctionDefinition {
+                                                name: m.node(309, 310, "f"),
+                                                params: NodeList::empty(),
+                                                returns: m.list([
+                                                    m.node(316, 317, "r"),
+                                                ]),
+                                                body: m.node(318, 417, InlineAssemblyBlock {
+                                                    items: m.list([
+                                                        m.node(348, 357, AssemblyAssignment {
+                                                            id: m.node(348, 349, "r"),
+                                                            init: m.node(353, 357, AssemblyItem::BoolLiteral(true)),
+                                                        }),
+                                                        m.node(386, 391, AssemblyItem::Leave),
+                                                    ]),
+                                                }),
+                                            }),
+                                            m.node(442, 456, AssemblyLocalBinding {
+                                                id: m.node(446, 447, "s"),
+                                                init: m.node(451, 456, AssemblyItem::StringLiteral("\"foo\"")),
+                                            }),
+                                            m.node(481, 501, AssemblyLocalBinding {
+                                                id: m.node(485, 486, "h"),
+                                                init: m.node(490, 501, AssemblyItem::HexLiteral("hex\"c0ffee\"")),
+                                            }),
+                                        ]),
+                                    }),
+                                }),
+                            ]),
+                        }),
+                    }),
+                ]),
+            }),
+        ]);
+    }
+}
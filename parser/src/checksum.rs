@@ -0,0 +1,68 @@
+use tiny_keccak::keccak256;
+
+use ast::{Warning, WarningKind};
+use Parser;
+
+impl<'ast> Parser<'ast> {
+    /// If checksums are being checked, produce a warning when the current
+    /// hex literal is an address with casing that doesn't match its EIP-55
+    /// checksum.
+    pub fn check_checksum(&mut self) {
+        if !self.checksums || is_valid_checksum(&self.lexer.slice()[2..]) {
+            return;
+        }
+
+        let (start, end) = self.loc();
+
+        self.warnings.push(Warning {
+            kind: WarningKind::AddressChecksum,
+            start,
+            end,
+        });
+    }
+}
+
+/// Address literals have exactly 40 hex digits, anything else is not
+/// an address and doesn't need a checksum.
+fn is_valid_checksum(digits: &str) -> bool {
+    if digits.len() != 40 {
+        return true;
+    }
+
+    let lowercase = digits.to_ascii_lowercase();
+    let hash      = keccak256(lowercase.as_bytes());
+
+    digits.bytes().enumerate().all(|(index, digit)| {
+        let nibble = match index % 2 {
+            0 => hash[index / 2] >> 4,
+            _ => hash[index / 2] & 0x0F,
+        };
+
+        match digit {
+            b'a'..=b'f' => nibble < 8,
+            b'A'..=b'F' => nibble >= 8,
+            _           => true,
+        }
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use parse_with;
+
+    #[test]
+    fn address_checksums() {
+        let source = "contract Foo { function() { \
+            a = 0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed; \
+            b = 0x5aaeb6053F3E94C9b9A09f33669435E7Ef1BeAed; \
+            c = 0xDEAD; \
+        } }";
+
+        let program = parse_with(source, |parser| parser.checksums()).unwrap();
+
+        assert_eq!(program.warnings(), &[
+            Warning { kind: WarningKind::AddressChecksum, start: 80, end: 122 },
+        ]);
+    }
+}
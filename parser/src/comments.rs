@@ -0,0 +1,76 @@
+use ast::{Comment, CommentKind};
+
+/// Collect all comments in the `start..end` gap between two tokens. Since
+/// the lexer skips comments along with whitespace, that's the only place
+/// they can be found.
+pub fn gap_comments(source: &str, mut start: usize, end: usize, comments: &mut Vec<Comment>) {
+    let bytes = source.as_bytes();
+
+    while start < end {
+        let rest = &bytes[start..end];
+
+        let (kind, len) = if rest.starts_with(b"//") {
+            let len = rest.iter().position(|&byte| byte == b'\n').unwrap_or(rest.len());
+
+            match rest.starts_with(b"///") {
+                true  => (CommentKind::Doc, len),
+                false => (CommentKind::Line, len),
+            }
+        } else if rest.starts_with(b"/*") {
+            let len = rest[2..].windows(2)
+                .position(|window| window == b"*/")
+                .map(|position| position + 4)
+                .unwrap_or(rest.len());
+
+            match rest.starts_with(b"/**") && !rest.starts_with(b"/**/") {
+                true  => (CommentKind::Doc, len),
+                false => (CommentKind::Block, len),
+            }
+        } else {
+            start += 1;
+
+            continue;
+        };
+
+        comments.push(Comment {
+            kind,
+            start: start as u32,
+            end: (start + len) as u32,
+        });
+
+        start += len;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use parse_with;
+
+    #[test]
+    fn collects_comments() {
+        let source = "// line\ncontract Foo { /* block */ }\n/// doc\n";
+
+        let program = parse_with(source, |parser| parser.comments()).unwrap();
+
+        assert_eq!(program.comments(), &[
+            Comment { kind: CommentKind::Line, start: 0, end: 7 },
+            Comment { kind: CommentKind::Block, start: 23, end: 34 },
+            Comment { kind: CommentKind::Doc, start: 37, end: 44 },
+        ]);
+    }
+
+    #[test]
+    fn comments_dont_affect_tokens() {
+        let source = "/** doc */ contract /**/ Foo {}";
+
+        let program = parse_with(source, |parser| parser.comments()).unwrap();
+
+        assert_eq!(program.comments(), &[
+            Comment { kind: CommentKind::Doc, start: 0, end: 10 },
+            Comment { kind: CommentKind::Block, start: 20, end: 24 },
+        ]);
+
+        assert_eq!(program.body().iter().next().unwrap().start, 11);
+    }
+}
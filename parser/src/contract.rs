@@ -3,17 +3,43 @@ use toolshed::list::{ListBuilder, GrowableList};
 use ast::*;
 use {Parser, ModifierContext, TOP, RegularTypeNameContext};
 use lexer::Token;
+use version::CUSTOM_ERRORS;
 
 impl<'ast> Parser<'ast> {
     pub fn contract_definition(&mut self) -> Option<SourceUnitNode<'ast>> {
-        let start = self.start_then_advance();
+        let start = self.lexer.range().start as u32;
+
+        let is_abstract = match self.lexer.token {
+            Token::DeclarationContract => {
+                self.lexer.advance();
+
+                None
+            },
+            _ => {
+                let flag = self.node_at_token(Flag);
+
+                match self.lexer.token {
+                    Token::DeclarationContract => self.lexer.advance(),
+
+                    // Only contracts can be abstract, report it and carry on as if it was one
+                    Token::DeclarationInterface | Token::DeclarationLibrary => {
+                        self.error();
+                        self.lexer.advance();
+                    },
+                    _ => self.error(),
+                }
+
+                Some(flag)
+            },
+        };
+
         let name = self.expect_str_node(Token::Identifier);
 
         let inherits = if self.allow(Token::KeywordIs) {
-            let builder = ListBuilder::new(self.arena, self.expect_str_node(Token::Identifier));
+            let builder = ListBuilder::new(self.arena, self.inheritance_specifier());
 
             while self.allow(Token::Comma) {
-                builder.push(self.arena, self.expect_str_node(Token::Identifier));
+                builder.push(self.arena, self.inheritance_specifier());
             }
 
             builder.as_list()
@@ -32,26 +58,83 @@ impl<'ast> Parser<'ast> {
         let end = self.expect_end(Token::BraceClose);
 
         self.node_at(start, end, ContractDefinition {
+            is_abstract,
             name,
             inherits,
             body: builder.as_list(),
         })
     }
 
+    fn inheritance_specifier(&mut self) -> Node<'ast, InheritanceSpecifier<'ast>> {
+        let name = self.expect_str_node(Token::Identifier);
+
+        let arguments;
+        let end;
+
+        if self.allow(Token::ParenOpen) {
+            arguments = self.expression_list();
+            end       = self.expect_end(Token::ParenClose);
+        } else {
+            arguments = NodeList::empty();
+            end       = name.end;
+        }
+
+        self.node_at(name.start, end, InheritanceSpecifier {
+            name,
+            arguments,
+        })
+    }
+
     fn contract_part(&mut self) -> Option<ContractPartNode<'ast>> {
         match self.lexer.token {
-            Token::KeywordUsing        => self.using_for_declaration(),
-            Token::DeclarationStruct   => self.struct_defintion(),
-            Token::DeclarationModifier => self.modifier_definition(),
-            Token::DeclarationFunction => self.function_definition(),
-            Token::DeclarationEvent    => self.event_definition(),
-            Token::DeclarationEnum     => self.enum_definition(),
-            _                          => self.state_variable_declaration(),
+            Token::KeywordUsing           => self.using_for_declaration(),
+            Token::DeclarationStruct      => self.struct_defintion(),
+            Token::DeclarationModifier    => self.modifier_definition(),
+            Token::DeclarationFunction    => self.function_definition(),
+            Token::DeclarationConstructor => self.constructor_definition(),
+            Token::DeclarationEvent       => self.event_definition(),
+            Token::DeclarationEnum        => self.enum_definition(),
+            Token::Identifier             => match self.lexer.slice() {
+                "receive"  => self.special_function_definition(SpecialFunction::Receive),
+                "fallback" => self.special_function_definition(SpecialFunction::Fallback),
+                "error"    => self.error_definition(),
+                _          => self.state_variable_declaration(),
+            },
+            _                             => self.state_variable_declaration(),
         }
 
     }
 
     fn state_variable_declaration(&mut self) -> Option<ContractPartNode<'ast>> {
+        let (start, end, declaration) = self.state_variable()?;
+
+        self.node_at(start, end, declaration)
+    }
+
+    /// Only constants can be declared at file level, and those can't have visibility.
+    pub fn file_level_constant(&mut self) -> Option<SourceUnitNode<'ast>> {
+        let (start, end, declaration) = self.state_variable()?;
+
+        if let Some(visibility) = declaration.visibility {
+            let token = match visibility.value {
+                StateVariableVisibility::Public   => Token::KeywordPublic,
+                StateVariableVisibility::Internal => Token::KeywordInternal,
+                StateVariableVisibility::Private  => Token::KeywordPrivate,
+            };
+
+            self.error_at(token, visibility.start as usize..visibility.end as usize);
+        }
+
+        if declaration.constant.is_none() {
+            let name = declaration.name;
+
+            self.error_at(Token::Identifier, name.start as usize..name.end as usize);
+        }
+
+        self.node_at(start, end, declaration)
+    }
+
+    fn state_variable(&mut self) -> Option<(u32, u32, StateVariableDeclaration<'ast>)> {
         let type_name = self.type_name::<RegularTypeNameContext>()?;
 
         let mut visibility = None;
@@ -62,13 +145,27 @@ impl<'ast> Parser<'ast> {
                 Token::KeywordPublic   => self.unique_flag(&mut visibility, StateVariableVisibility::Public),
                 Token::KeywordInternal => self.unique_flag(&mut visibility, StateVariableVisibility::Internal),
                 Token::KeywordPrivate  => self.unique_flag(&mut visibility, StateVariableVisibility::Private),
-                Token::KeywordConstant => self.unique_flag(&mut constant, Flag),
+                Token::KeywordConstant => {
+                    // Mappings can't be constant, report it and parse the rest anyway
+                    if let TypeName::Mapping(_) = type_name.value {
+                        self.error();
+                    }
+
+                    self.unique_flag(&mut constant, Flag)
+                },
                 _                      => break,
             }
         }
 
         let name = self.expect_str_node(Token::Identifier);
 
+        // Mappings can't be initialized, report it at `=` and parse the rest anyway
+        if let TypeName::Mapping(_) = type_name.value {
+            if self.lexer.token == Token::Assign {
+                self.error();
+            }
+        }
+
         let init = if self.allow(Token::Assign) {
             match self.expression(TOP) {
                 None => {
@@ -84,18 +181,35 @@ impl<'ast> Parser<'ast> {
 
         let end = self.expect_end(Token::Semicolon);
 
-        self.node_at(type_name.start, end, StateVariableDeclaration {
+        Some((type_name.start, end, StateVariableDeclaration {
             type_name,
             visibility,
             constant,
             name,
             init,
-        })
+        }))
     }
 
-    fn using_for_declaration(&mut self) -> Option<ContractPartNode<'ast>> {
+    /// `R` should be either `ContractPart` or `SourceUnit`
+    pub fn using_for_declaration<R>(&mut self) -> Option<Node<'ast, R>>
+    where
+        R: From<UsingForDeclaration<'ast>> + Copy,
+    {
         let start = self.start_then_advance();
-        let id    = self.expect_str_node(Token::Identifier);
+
+        let (id, functions) = if self.allow(Token::BraceOpen) {
+            let builder = ListBuilder::new(self.arena, expect!(self, self.using_function()));
+
+            while self.allow(Token::Comma) {
+                builder.push(self.arena, expect!(self, self.using_function()));
+            }
+
+            self.expect(Token::BraceClose);
+
+            (None, builder.as_list())
+        } else {
+            (Some(self.expect_str_node(Token::Identifier)), NodeList::empty())
+        };
 
         self.expect(Token::KeywordFor);
 
@@ -108,11 +222,59 @@ impl<'ast> Parser<'ast> {
             type_name => type_name,
         };
 
+        let global = match self.lexer.token {
+            Token::Identifier if self.lexer.slice() == "global" => self.node_at_token(Flag),
+            _                                                  => None,
+        };
+
         let end = self.expect_end(Token::Semicolon);
 
         self.node_at(start, end, UsingForDeclaration {
             id,
+            functions,
             type_name,
+            global,
+        })
+    }
+
+    fn using_function(&mut self) -> Option<Node<'ast, UsingFunction<'ast>>> {
+        let function = self.allow_str_node(Token::Identifier)?;
+
+        if !self.allow(Token::KeywordAs) {
+            return self.node_at(function.start, function.end, UsingFunction {
+                function,
+                operator: None,
+            });
+        }
+
+        let operator = match self.lexer.token {
+            Token::OperatorBitAnd         => UserOperator::BitAnd,
+            Token::OperatorBitOr          => UserOperator::BitOr,
+            Token::OperatorBitXor         => UserOperator::BitXor,
+            Token::OperatorBitNot         => UserOperator::BitNot,
+            Token::OperatorAddition       => UserOperator::Addition,
+            Token::OperatorSubtraction    => UserOperator::Subtraction,
+            Token::OperatorMultiplication => UserOperator::Multiplication,
+            Token::OperatorDivision       => UserOperator::Division,
+            Token::OperatorRemainder      => UserOperator::Remainder,
+            Token::OperatorEquality       => UserOperator::Equality,
+            Token::OperatorInequality     => UserOperator::Inequality,
+            Token::OperatorLesser         => UserOperator::Lesser,
+            Token::OperatorLesserEquals   => UserOperator::LesserEquals,
+            Token::OperatorGreater        => UserOperator::Greater,
+            Token::OperatorGreaterEquals  => UserOperator::GreaterEquals,
+            _ => {
+                self.error();
+
+                return None;
+            },
+        };
+
+        let operator: Node<_> = self.node_at_token(operator);
+
+        self.node_at(function.start, operator.end, UsingFunction {
+            function,
+            operator: Some(operator),
         })
     }
 
@@ -166,16 +328,65 @@ impl<'ast> Parser<'ast> {
             params = NodeList::empty()
         }
 
+        let mut is_virtual = None;
+        let mut overrides  = None;
+
+        loop {
+            match (self.lexer.token, self.lexer.slice()) {
+                (Token::Identifier, "virtual")  => self.unique_flag(&mut is_virtual, Flag),
+                (Token::Identifier, "override") => self.unique_override(&mut overrides),
+                _                               => break,
+            }
+        }
+
         let block = self.block::<ModifierContext, _>();
 
         self.node_at(start, block.end, ModifierDefinition {
             name,
             params,
+            is_virtual,
+            overrides,
             block,
         })
     }
 
-    fn event_definition(&mut self) -> Option<ContractPartNode<'ast>> {
+    pub fn unique_override(&mut self, at: &mut Option<OverrideSpecifierNode<'ast>>) {
+        if at.is_some() {
+            self.error();
+        }
+
+        *at = Some(self.override_specifier());
+    }
+
+    fn override_specifier(&mut self) -> OverrideSpecifierNode<'ast> {
+        let (start, mut end) = self.loc();
+
+        self.lexer.advance();
+
+        let bases = if self.allow(Token::ParenOpen) {
+            let builder = ListBuilder::new(self.arena, self.expect_str_node(Token::Identifier));
+
+            while self.allow(Token::Comma) {
+                builder.push(self.arena, self.expect_str_node(Token::Identifier));
+            }
+
+            end = self.expect_end(Token::ParenClose);
+
+            builder.as_list()
+        } else {
+            NodeList::empty()
+        };
+
+        self.node_at(start, end, OverrideSpecifier {
+            bases,
+        })
+    }
+
+    /// `R` should be either `ContractPart` or `SourceUnit`
+    pub fn event_definition<R>(&mut self) -> Option<Node<'ast, R>>
+    where
+        R: From<EventDefinition<'ast>> + Copy,
+    {
         let start  = self.start_then_advance();
         let name   = self.expect_str_node(Token::Identifier);
 
@@ -209,8 +420,42 @@ impl<'ast> Parser<'ast> {
         })
     }
 
+    /// `R` should be either `ContractPart` or `SourceUnit`
+    pub fn error_definition<R>(&mut self) -> Option<Node<'ast, R>>
+    where
+        R: From<ErrorDefinition<'ast>> + Copy,
+    {
+        let span = self.lexer.range();
+
+        self.require_version(CUSTOM_ERRORS, Token::Identifier, span);
+
+        let start = self.start_then_advance();
+        let name  = self.expect_str_node(Token::Identifier);
+
+        self.expect(Token::ParenOpen);
+
+        let params = self.parameter_list();
+
+        self.expect(Token::ParenClose);
+
+        let end = self.expect_end(Token::Semicolon);
+
+        self.node_at(start, end, ErrorDefinition {
+            name,
+            params,
+        })
+    }
+
     fn indexed_parameter(&mut self) -> Option<Node<'ast, IndexedParameter<'ast>>> {
         let type_name = self.type_name::<RegularTypeNameContext>()?;
+
+        // Event parameters can't have a storage location, report it and
+        // carry on as if it wasn't there.
+        if let Token::KeywordMemory | Token::KeywordStorage | Token::KeywordCalldata = self.lexer.token {
+            self.error();
+            self.lexer.advance();
+        }
+
         let indexed   = self.allow_flag_node(Token::KeywordIndexed);
         let name      = self.allow_str_node(Token::Identifier);
 
@@ -234,7 +479,18 @@ impl<'ast> Parser<'ast> {
         let variants = if let Some(variant) = self.allow_str_node(Token::Identifier) {
             let builder = ListBuilder::new(self.arena, variant);
 
-            while self.allow(Token::Comma) {
+            while self.lexer.token == Token::Comma {
+                let span = self.lexer.range();
+
+                self.lexer.advance();
+
+                // Trailing commas aren't allowed, point the error at the comma
+                if self.lexer.token == Token::BraceClose {
+                    self.error_at(Token::Comma, span);
+
+                    break;
+                }
+
                 builder.push(self.arena, self.expect_str_node(Token::Identifier))
             }
 
@@ -255,7 +511,7 @@ impl<'ast> Parser<'ast> {
 #[cfg(test)]
 mod test {
     use super::*;
-    use mock::{Mock, assert_units};
+    use mock::{Mock, assert_units, parse_err};
 
     #[test]
     fn empty_contract() {
@@ -269,28 +525,144 @@ mod test {
 
         "#, [
             m.node(14, 29, ContractDefinition {
+                is_abstract: None,
                 name: m.node(23, 26, "Foo"),
                 inherits: NodeList::empty(),
                 body: NodeList::empty(),
             }),
             m.node(42, 69, ContractDefinition {
+                is_abstract: None,
                 name: m.node(51, 55, "Doge"),
                 inherits: m.list([
-                    m.node(59, 66, "Amazing"),
+                    m.node(59, 66, InheritanceSpecifier {
+                        name: m.node(59, 66, "Amazing"),
+                        arguments: NodeList::empty(),
+                    }),
                 ]),
                 body: NodeList::empty(),
             }),
             m.node(82, 114, ContractDefinition {
+                is_abstract: None,
                 name: m.node(91, 95, "This"),
                 inherits: m.list([
-                    m.node(99, 104, "Silly"),
-                    m.node(106, 111, "Kinda"),
+                    m.node(99, 104, InheritanceSpecifier {
+                        name: m.node(99, 104, "Silly"),
+                        arguments: NodeList::empty(),
+                    }),
+                    m.node(106, 111, InheritanceSpecifier {
+                        name: m.node(106, 111, "Kinda"),
+                        arguments: NodeList::empty(),
+                    }),
+                ]),
+                body: NodeList::empty(),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn abstract_contract_with_base_arguments() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            abstract contract C is A, B(1) { }
+
+        "#, [
+            m.node(14, 48, ContractDefinition {
+                is_abstract: m.node(14, 22, Flag),
+                name: m.node(32, 33, "C"),
+                inherits: m.list([
+                    m.node(37, 38, InheritanceSpecifier {
+                        name: m.node(37, 38, "A"),
+                        arguments: NodeList::empty(),
+                    }),
+                    m.node(40, 44, InheritanceSpecifier {
+                        name: m.node(40, 41, "B"),
+                        arguments: m.list([
+                            m.node(42, 43, Primitive::IntegerNumber("1", NumberUnit::None)),
+                        ]),
+                    }),
+                ]),
+                body: NodeList::empty(),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn inheritance_on_one_line() {
+        let m = Mock::new();
+
+        assert_units("contract C is A, B {}", [
+            m.node(0, 21, ContractDefinition {
+                is_abstract: None,
+                name: m.node(9, 10, "C"),
+                inherits: m.list([
+                    m.node(14, 15, InheritanceSpecifier {
+                        name: m.node(14, 15, "A"),
+                        arguments: NodeList::empty(),
+                    }),
+                    m.node(17, 18, InheritanceSpecifier {
+                        name: m.node(17, 18, "B"),
+                        arguments: NodeList::empty(),
+                    }),
                 ]),
                 body: NodeList::empty(),
             }),
         ]);
     }
 
+    #[test]
+    fn self_named_base_is_only_syntax() {
+        let m = Mock::new();
+
+        assert_units("contract A is A {}", [
+            m.node(0, 18, ContractDefinition {
+                is_abstract: None,
+                name: m.node(9, 10, "A"),
+                inherits: m.list([
+                    m.node(14, 15, InheritanceSpecifier {
+                        name: m.node(14, 15, "A"),
+                        arguments: NodeList::empty(),
+                    }),
+                ]),
+                body: NodeList::empty(),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn only_contracts_can_be_abstract() {
+        use error::Error;
+
+        for &(source, raw, token) in &[
+            ("abstract interface I {}", "interface", Token::DeclarationInterface),
+            ("abstract library L {}", "library", Token::DeclarationLibrary),
+        ] {
+            let errors = parse_err(source);
+
+            assert_eq!(errors, vec![Error {
+                token,
+                raw: raw.into(),
+                span: 9..9 + raw.len(),
+                since: None,
+            }]);
+        }
+    }
+
+    #[test]
+    fn contract_without_trailing_newline() {
+        let m = Mock::new();
+
+        assert_units("contract Foo {}", [
+            m.node(0, 15, ContractDefinition {
+                is_abstract: None,
+                name: m.node(9, 12, "Foo"),
+                inherits: NodeList::empty(),
+                body: NodeList::empty(),
+            }),
+        ]);
+    }
+
     #[test]
     fn state_variable_declaration() {
         let m = Mock::new();
@@ -304,6 +676,7 @@ mod test {
 
         "#, [
             m.node(14, 111, ContractDefinition {
+                is_abstract: None,
                 name: m.node(23, 26, "Foo"),
                 inherits: NodeList::empty(),
                 body: m.list([
@@ -326,6 +699,92 @@ mod test {
         ]);
     }
 
+    #[test]
+    fn state_variable_initialized_to_call() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                uint256 public total = calcInitial();
+            }
+
+        "#, [
+            m.node(14, 96, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 82, StateVariableDeclaration {
+                        type_name: m.node(45, 52, ElementaryTypeName::Uint(32)),
+                        visibility: m.node(53, 59, StateVariableVisibility::Public),
+                        constant: None,
+                        name: m.node(60, 65, "total"),
+                        init: m.node(68, 81, CallExpression {
+                            callee: m.node(68, 79, "calcInitial"),
+                            arguments: NodeList::empty(),
+                        }),
+                    }),
+                ]),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn public_mapping_state_variable() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                mapping(address => uint256) public balances;
+            }
+
+        "#, [
+            m.node(14, 103, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 89, StateVariableDeclaration {
+                        type_name: m.node(45, 72, Mapping {
+                            from: m.node(53, 60, ElementaryTypeName::Address),
+                            from_name: None,
+                            to: m.node(64, 71, ElementaryTypeName::Uint(32)),
+                            to_name: None,
+                        }),
+                        visibility: m.node(73, 79, StateVariableVisibility::Public),
+                        constant: None,
+                        name: m.node(80, 88, "balances"),
+                        init: None,
+                    }),
+                ]),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn mapping_cannot_be_initialized() {
+        let errors = parse_err("contract Foo { mapping(address => uint) m = 1; }");
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].token, Token::Assign);
+        assert_eq!(errors[0].span, 42..43);
+    }
+
+    #[test]
+    fn mapping_cannot_be_constant() {
+        use parse;
+
+        let errors = parse_err("contract Foo { mapping(address => uint) constant m; }");
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].token, Token::KeywordConstant);
+        assert_eq!(errors[0].span, 40..48);
+
+        assert!(parse("contract Foo { mapping(address => uint) public m; }").is_ok());
+    }
+
     #[test]
     fn using_for_declaration() {
         let m = Mock::new();
@@ -339,16 +798,21 @@ mod test {
 
         "#, [
             m.node(14, 112, ContractDefinition {
+                is_abstract: None,
                 name: m.node(23, 26, "Foo"),
                 inherits: NodeList::empty(),
                 body: m.list([
                     m.node(45, 61, UsingForDeclaration {
                         id: m.node(51, 54, "foo"),
+                        functions: NodeList::empty(),
                         type_name: None,
+                        global: None,
                     }),
                     m.node(78, 98, UsingForDeclaration {
                         id: m.node(84, 87, "bar"),
+                        functions: NodeList::empty(),
                         type_name: m.node(92, 97, ElementaryTypeName::Int(4)),
+                        global: None,
                     }),
                 ]),
             }),
@@ -371,6 +835,7 @@ mod test {
 
         "#, [
             m.node(14, 202, ContractDefinition {
+                is_abstract: None,
                 name: m.node(23, 26, "Foo"),
                 inherits: NodeList::empty(),
                 body: m.list([
@@ -399,6 +864,96 @@ mod test {
         ]);
     }
 
+    #[test]
+    fn struct_with_mapping_and_array_members() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                struct S {
+                    mapping(uint => uint) m;
+                    uint[] arr;
+                }
+            }
+
+        "#, [
+            m.node(14, 164, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 150, StructDefinition {
+                        name: m.node(52, 53, "S"),
+                        body: m.list([
+                            m.node(76, 99, VariableDeclaration {
+                                type_name: m.node(76, 97, Mapping {
+                                    from: m.node(84, 88, ElementaryTypeName::Uint(32)),
+                                    from_name: None,
+                                    to: m.node(92, 96, ElementaryTypeName::Uint(32)),
+                                    to_name: None,
+                                }),
+                                location: None,
+                                id: m.node(98, 99, "m"),
+                            }),
+                            m.node(121, 131, VariableDeclaration {
+                                type_name: m.node(121, 127, ArrayTypeName {
+                                    type_name: m.node(121, 125, ElementaryTypeName::Uint(32)),
+                                    length: None,
+                                }),
+                                location: None,
+                                id: m.node(128, 131, "arr"),
+                            }),
+                        ])
+                    }),
+                ]),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn struct_with_nested_mapping_member() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                struct S {
+                    mapping(uint => mapping(uint => bool)) nested;
+                }
+            }
+
+        "#, [
+            m.node(14, 154, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 140, StructDefinition {
+                        name: m.node(52, 53, "S"),
+                        body: m.list([
+                            m.node(76, 121, VariableDeclaration {
+                                type_name: m.node(76, 114, Mapping {
+                                    from: m.node(84, 88, ElementaryTypeName::Uint(32)),
+                                    from_name: None,
+                                    to: m.node(92, 113, Mapping {
+                                        from: m.node(100, 104, ElementaryTypeName::Uint(32)),
+                                        from_name: None,
+                                        to: m.node(108, 112, ElementaryTypeName::Bool),
+                                        to_name: None,
+                                    }),
+                                    to_name: None,
+                                }),
+                                location: None,
+                                id: m.node(115, 121, "nested"),
+                            }),
+                        ])
+                    }),
+                ]),
+            }),
+        ]);
+    }
+
     #[test]
     fn modifier_definition() {
         let m = Mock::new();
@@ -416,12 +971,15 @@ mod test {
 
         "#, [
             m.node(14, 206, ContractDefinition {
+                is_abstract: None,
                 name: m.node(23, 26, "Foo"),
                 inherits: NodeList::empty(),
                 body: m.list([
                     m.node(45, 71, ModifierDefinition {
                         name: m.node(54, 64, "only_doges"),
                         params: NodeList::empty(),
+                        is_virtual: None,
+                        overrides: None,
                         block: m.node(65, 71, Block {
                             body: m.list([
                                 m.node(67, 69, Statement::Placeholder),
@@ -433,9 +991,12 @@ mod test {
                         params: m.list([
                             m.node(102, 111, Parameter {
                                 type_name: m.node(102, 107, ElementaryTypeName::Uint(1)),
+                                location: None,
                                 name: m.node(108, 111, "bar"),
                             }),
                         ]),
+                        is_virtual: None,
+                        overrides: None,
                         block: m.node(113, 192, Block {
                             body: m.list([
                                 m.node(135, 151, VariableDefinitionStatement {
@@ -455,6 +1016,55 @@ mod test {
         ]);
     }
 
+    #[test]
+    fn virtual_and_override_modifiers() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                modifier m() virtual { _; }
+                modifier n override(A, B) virtual { _; }
+            }
+
+        "#, [
+            m.node(14, 143, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 72, ModifierDefinition {
+                        name: m.node(54, 55, "m"),
+                        params: NodeList::empty(),
+                        is_virtual: m.node(58, 65, Flag),
+                        overrides: None,
+                        block: m.node(66, 72, Block {
+                            body: m.list([
+                                m.node(68, 70, Statement::Placeholder),
+                            ]),
+                        }),
+                    }),
+                    m.node(89, 129, ModifierDefinition {
+                        name: m.node(98, 99, "n"),
+                        params: NodeList::empty(),
+                        is_virtual: m.node(115, 122, Flag),
+                        overrides: m.node(100, 114, OverrideSpecifier {
+                            bases: m.list([
+                                m.node(109, 110, "A"),
+                                m.node(112, 113, "B"),
+                            ]),
+                        }),
+                        block: m.node(123, 129, Block {
+                            body: m.list([
+                                m.node(125, 127, Statement::Placeholder),
+                            ]),
+                        }),
+                    }),
+                ]),
+            }),
+        ]);
+    }
+
     #[test]
     fn empty_events() {
         let m = Mock::new();
@@ -468,6 +1078,7 @@ mod test {
 
         "#, [
             m.node(14, 121, ContractDefinition {
+                is_abstract: None,
                 name: m.node(23, 26, "Foo"),
                 inherits: NodeList::empty(),
                 body: m.list([
@@ -498,6 +1109,7 @@ mod test {
 
         "#, [
             m.node(14, 94, ContractDefinition {
+                is_abstract: None,
                 name: m.node(23, 26, "Foo"),
                 inherits: NodeList::empty(),
                 body: m.list([
@@ -534,6 +1146,7 @@ mod test {
 
         "#, [
             m.node(14, 102, ContractDefinition {
+                is_abstract: None,
                 name: m.node(23, 26, "Foo"),
                 inherits: NodeList::empty(),
                 body: m.list([
@@ -558,6 +1171,105 @@ mod test {
         ]);
     }
 
+    #[test]
+    fn event_with_mixed_parameter_names() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                event E(uint, address indexed who, bool indexed);
+            }
+
+        "#, [
+            m.node(14, 108, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 94, EventDefinition {
+                        anonymous: None,
+                        name: m.node(51, 52, "E"),
+                        params: m.list([
+                            m.node(53, 57, IndexedParameter {
+                                type_name: m.node(53, 57, ElementaryTypeName::Uint(32)),
+                                indexed: None,
+                                name: None,
+                            }),
+                            m.node(59, 78, IndexedParameter {
+                                type_name: m.node(59, 66, ElementaryTypeName::Address),
+                                indexed: m.node(67, 74, Flag),
+                                name: m.node(75, 78, "who"),
+                            }),
+                            m.node(80, 92, IndexedParameter {
+                                type_name: m.node(80, 84, ElementaryTypeName::Bool),
+                                indexed: m.node(85, 92, Flag),
+                                name: None,
+                            }),
+                        ]),
+                    }),
+                ]),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn indexed_limit_is_left_to_later_stages() {
+        let m = Mock::new();
+
+        // Only 3 parameters can be indexed, but that's not a syntactic restriction
+        assert_units(r#"
+
+            contract Foo {
+                event E(uint indexed a, uint indexed b, uint indexed c, uint indexed d);
+            }
+
+        "#, [
+            m.node(14, 131, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 117, EventDefinition {
+                        anonymous: None,
+                        name: m.node(51, 52, "E"),
+                        params: m.list([
+                            m.node(53, 67, IndexedParameter {
+                                type_name: m.node(53, 57, ElementaryTypeName::Uint(32)),
+                                indexed: m.node(58, 65, Flag),
+                                name: m.node(66, 67, "a"),
+                            }),
+                            m.node(69, 83, IndexedParameter {
+                                type_name: m.node(69, 73, ElementaryTypeName::Uint(32)),
+                                indexed: m.node(74, 81, Flag),
+                                name: m.node(82, 83, "b"),
+                            }),
+                            m.node(85, 99, IndexedParameter {
+                                type_name: m.node(85, 89, ElementaryTypeName::Uint(32)),
+                                indexed: m.node(90, 97, Flag),
+                                name: m.node(98, 99, "c"),
+                            }),
+                            m.node(101, 115, IndexedParameter {
+                                type_name: m.node(101, 105, ElementaryTypeName::Uint(32)),
+                                indexed: m.node(106, 113, Flag),
+                                name: m.node(114, 115, "d"),
+                            }),
+                        ]),
+                    }),
+                ]),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn event_parameters_cannot_have_storage_location() {
+        let errors = parse_err("contract Foo { event Bar(uint memory indexed x); }");
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].token, Token::KeywordMemory);
+        assert_eq!(errors[0].span, 30..36);
+    }
+
     #[test]
     fn enum_definition() {
         let m = Mock::new();
@@ -571,6 +1283,7 @@ mod test {
 
         "#, [
             m.node(14, 116, ContractDefinition {
+                is_abstract: None,
                 name: m.node(23, 26, "Foo"),
                 inherits: NodeList::empty(),
                 body: m.list([
@@ -590,4 +1303,17 @@ mod test {
             }),
         ]);
     }
+
+    #[test]
+    fn enum_trailing_comma() {
+        use parse;
+
+        assert!(parse("contract Foo { enum E { A, B } }").is_ok());
+
+        let errors = parse_err("contract Foo { enum E { A, B, } }");
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].token, Token::Comma);
+        assert_eq!(errors[0].span, 28..29);
+    }
 }
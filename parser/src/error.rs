@@ -1,6 +1,7 @@
 use std::fmt::{self, Debug};
 use std::ops::Range;
 use Token;
+use version::Version;
 
 /// Error type used by the tokenizer and the parser internally.
 #[derive(PartialEq, Clone)]
@@ -8,11 +9,18 @@ pub struct Error {
     pub token: Token,
     pub raw: Box<str>,
     pub span: Range<usize>,
+
+    /// Set if the syntax is only supported since this Solidity version,
+    /// which is newer than the one targeted by the parser.
+    pub since: Option<Version>,
 }
 
 impl Debug for Error {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "Unexpected {:?}({}) at {}:{}", &self.token, &*self.raw, self.span.start, self.span.end)
+        match self.since {
+            Some(version) => write!(f, "{} requires Solidity {} at {}:{}", &*self.raw, version, self.span.start, self.span.end),
+            None          => write!(f, "Unexpected {:?}({}) at {}:{}", &self.token, &*self.raw, self.span.start, self.span.end),
+        }
     }
 }
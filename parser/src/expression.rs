@@ -1,7 +1,7 @@
-use toolshed::list::ListBuilder;
+use toolshed::list::{List, ListBuilder};
 
 use ast::*;
-use {Parser, Precedence, P2, TOP};
+use {Parser, Precedence, P2, TOP, RegularTypeNameContext};
 use lexer::{Token, Logos, lookup};
 
 type HandlerFn = for<'ast> fn(&mut Parser<'ast>) -> Option<ExpressionNode<'ast>>;
@@ -10,7 +10,22 @@ static EXPRESSION_LUT: [HandlerFn; Token::SIZE] = lookup! {
     Token::KeywordThis         => |par| par.node_at_token(ThisExpression),
     Token::Identifier          => |par| par.node_from_slice(|ident| ident),
     Token::IdentifierBuiltin   => |par| par.node_from_slice(|ident| ident),
+
+    // Units only apply directly after a number literal, elsewhere they are names
+    Token::UnitEther           => |par| par.node_from_slice(|ident| ident),
+    Token::UnitFinney          => |par| par.node_from_slice(|ident| ident),
+    Token::UnitSzabo           => |par| par.node_from_slice(|ident| ident),
+    Token::UnitWei             => |par| par.node_from_slice(|ident| ident),
+    Token::UnitTimeYears       => |par| par.node_from_slice(|ident| ident),
+    Token::UnitTimeWeeks       => |par| par.node_from_slice(|ident| ident),
+    Token::UnitTimeDays        => |par| par.node_from_slice(|ident| ident),
+    Token::UnitTimeHours       => |par| par.node_from_slice(|ident| ident),
+    Token::UnitTimeMinutes     => |par| par.node_from_slice(|ident| ident),
+    Token::UnitTimeSeconds     => |par| par.node_from_slice(|ident| ident),
+
     Token::ParenOpen           => |par| par.tuple_expression(),
+    Token::KeywordType         => |par| par.type_expression(),
+    Token::KeywordNew          => |par| par.new_expression(),
     Token::OperatorLogicalNot  => |par| par.prefix_expression(PrefixOperator::LogicalNot),
     Token::OperatorBitNot      => |par| par.prefix_expression(PrefixOperator::BitNot),
     Token::KeywordDelete       => |par| par.prefix_expression(PrefixOperator::Delete),
@@ -20,12 +35,14 @@ static EXPRESSION_LUT: [HandlerFn; Token::SIZE] = lookup! {
     Token::OperatorSubtraction => |par| par.prefix_expression(PrefixOperator::Minus),
     Token::LiteralTrue         => |par| par.node_at_token(Primitive::Bool(true)),
     Token::LiteralFalse        => |par| par.node_at_token(Primitive::Bool(false)),
-    Token::LiteralHex          => |par| par.node_from_slice(|slice| Primitive::HexNumber(slice)),
+    Token::LiteralHex          => |par| par.hex_number(),
     Token::LiteralInteger      => |par| par.integer_number(),
-    Token::LiteralRational     => |par| par.node_from_slice(|slice| Primitive::RationalNumber(slice)),
-    Token::LiteralString       => |par| par.node_from_slice(|slice| Primitive::String(slice)),
+    Token::LiteralRational     => |par| par.node_from_slice(Primitive::RationalNumber),
+    Token::LiteralString       => |par| par.string_literal(Primitive::String, Primitive::StringConcatenation),
+    Token::LiteralHexString    => |par| par.string_literal(Primitive::HexString, Primitive::HexStringConcatenation),
     Token::TypeBool            => |par| par.node_at_token(ElementaryTypeName::Bool),
     Token::TypeAddress         => |par| par.node_at_token(ElementaryTypeName::Address),
+    Token::KeywordPayable      => |par| par.node_at_token(ElementaryTypeName::AddressPayable),
     Token::TypeString          => |par| par.node_at_token(ElementaryTypeName::String),
     Token::TypeByte            => |par| {
         let size = par.lexer.extras.0;
@@ -61,8 +78,20 @@ static EXPRESSION_LUT: [HandlerFn; Token::SIZE] = lookup! {
 impl<'ast> Parser<'ast> {
     #[inline]
     pub fn expression(&mut self, precedence: Precedence) -> Option<ExpressionNode<'ast>> {
-        EXPRESSION_LUT[self.lexer.token as usize](self)
-            .map(|expression| self.nested_expression(expression, precedence))
+        if self.depth >= self.max_depth {
+            self.error();
+
+            return None;
+        }
+
+        self.depth += 1;
+
+        let expression = EXPRESSION_LUT[self.lexer.token as usize](self)
+            .map(|expression| self.nested_expression(expression, precedence));
+
+        self.depth -= 1;
+
+        expression
     }
 
     #[inline]
@@ -72,10 +101,17 @@ impl<'ast> Parser<'ast> {
             None             => return NodeList::empty(),
         };
 
-        while self.allow(Token::Comma) {
+        while self.lexer.token == Token::Comma {
+            let comma = self.lexer.range();
+
+            self.lexer.advance();
+
             match self.expression(TOP) {
                 Some(expression) => builder.push(self.arena, expression),
-                None             => self.error(),
+
+                // Trailing commas aren't allowed, point the error at the comma
+                None if self.lexer.token == Token::ParenClose => self.error_at(Token::Comma, comma),
+                None => self.error(),
             }
         }
 
@@ -83,15 +119,49 @@ impl<'ast> Parser<'ast> {
     }
 
     fn tuple_expression(&mut self) -> Option<ExpressionNode<'ast>> {
-        let start       = self.start_then_advance();
-        let expressions = self.expression_list();
-        let end         = self.expect_end(Token::ParenClose);
+        let start = self.start_then_advance();
+
+        let expressions = if self.lexer.token == Token::ParenClose {
+            List::empty()
+        } else {
+            let builder = ListBuilder::new(self.arena, self.expression(TOP));
+
+            while self.allow(Token::Comma) {
+                builder.push(self.arena, self.expression(TOP));
+            }
+
+            builder.as_list()
+        };
+
+        let end = self.expect_end(Token::ParenClose);
 
         self.node_at(start, end, TupleExpression {
             expressions,
         })
     }
 
+    fn type_expression(&mut self) -> Option<ExpressionNode<'ast>> {
+        let start = self.start_then_advance();
+
+        self.expect(Token::ParenOpen);
+
+        let type_name = expect!(self, self.type_name::<RegularTypeNameContext>());
+        let end       = self.expect_end(Token::ParenClose);
+
+        self.node_at(start, end, TypeExpression {
+            type_name,
+        })
+    }
+
+    fn new_expression(&mut self) -> Option<ExpressionNode<'ast>> {
+        let start     = self.start_then_advance();
+        let type_name = expect!(self, self.type_name::<RegularTypeNameContext>());
+
+        self.node_at(start, type_name.end, NewExpression {
+            type_name,
+        })
+    }
+
     fn prefix_expression(&mut self, operator: PrefixOperator) -> Option<ExpressionNode<'ast>> {
         let operator: Node<_> = self.node_at_token(operator);
         let operand = expect!(self, self.expression(P2));
@@ -102,6 +172,12 @@ impl<'ast> Parser<'ast> {
         })
     }
 
+    fn hex_number(&mut self) -> Option<ExpressionNode<'ast>> {
+        self.check_checksum();
+
+        self.node_from_slice(Primitive::HexNumber)
+    }
+
     fn integer_number(&mut self) -> Option<ExpressionNode<'ast>> {
         let number = self.lexer.slice();
         let (start, end) = self.loc();
@@ -127,13 +203,38 @@ impl<'ast> Parser<'ast> {
 
         self.node_at(start, end, Primitive::IntegerNumber(number, unit))
     }
+
+    fn string_literal(
+        &mut self,
+        single: fn(&'ast str) -> Primitive<'ast>,
+        concatenation: fn(StringLiteralList<'ast>) -> Primitive<'ast>,
+    ) -> Option<ExpressionNode<'ast>> {
+        let token = self.lexer.token;
+        let first: StringLiteralNode<'ast> = self.str_node();
+
+        if self.lexer.token != token {
+            return self.node_at(first.start, first.end, single(first.value));
+        }
+
+        let builder = ListBuilder::new(self.arena, first);
+        let mut end = first.end;
+
+        while self.lexer.token == token {
+            let piece: StringLiteralNode<'ast> = self.str_node();
+
+            end = piece.end;
+            builder.push(self.arena, piece);
+        }
+
+        self.node_at(first.start, end, concatenation(builder.as_list()))
+    }
 }
 
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use mock::{Mock, assert_units};
+    use mock::{Mock, assert_units, parse_err};
 
     #[test]
     fn nested_expressions() {
@@ -155,6 +256,7 @@ mod test {
 
         "#, [
             m.node(14, 286, ContractDefinition {
+                is_abstract: None,
                 name: m.node(23, 26, "Foo"),
                 inherits: NodeList::empty(),
                 body: m.list([
@@ -163,6 +265,8 @@ mod test {
                         params: NodeList::empty(),
                         visibility: None,
                         mutability: None,
+                        is_virtual: None,
+                        overrides: None,
                         modifiers: NodeList::empty(),
                         returns: NodeList::empty(),
                         block: m.node(56, 272, Block {
@@ -202,4 +306,644 @@ mod test {
             }),
         ]);
     }
+
+    #[test]
+    fn delete_binds_to_postfix_expression() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                function() {
+                    delete a.b[c];
+                }
+            }
+
+        "#, [
+            m.node(14, 124, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 110, FunctionDefinition {
+                        name: None,
+                        params: NodeList::empty(),
+                        visibility: None,
+                        mutability: None,
+                        is_virtual: None,
+                        overrides: None,
+                        modifiers: NodeList::empty(),
+                        returns: NodeList::empty(),
+                        block: m.node(56, 110, Block {
+                            body: m.list([
+                                m.stmt_expr(78, 91, 92, PrefixExpression {
+                                    operator: m.node(78, 84, PrefixOperator::Delete),
+                                    operand: m.node(85, 91, IndexAccessExpression {
+                                        array: m.node(85, 88, MemberAccessExpression {
+                                            object: m.node(85, 86, "a"),
+                                            member: m.node(87, 88, "b"),
+                                        }),
+                                        index: m.node(89, 90, "c"),
+                                    }),
+                                }),
+                            ]),
+                        }),
+                    }),
+                ]),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn call_arguments_trailing_comma() {
+        use parse;
+        use error::Error;
+
+        let errors = parse_err("contract Foo { function() { f(a,); } }");
+
+        assert_eq!(errors, vec![Error {
+            token: Token::Comma,
+            raw: ",".into(),
+            span: 31..32,
+            since: None,
+        }]);
+
+        assert!(parse("contract Foo { function() { f(a, b); } }").is_ok());
+    }
+
+    #[test]
+    fn leading_dot_rational() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                function() {
+                    a = .5;
+                }
+            }
+
+        "#, [
+            m.node(14, 117, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 103, FunctionDefinition {
+                        name: None,
+                        params: NodeList::empty(),
+                        visibility: None,
+                        mutability: None,
+                        is_virtual: None,
+                        overrides: None,
+                        modifiers: NodeList::empty(),
+                        returns: NodeList::empty(),
+                        block: m.node(56, 103, Block {
+                            body: m.list([
+                                m.stmt_expr(78, 84, 85, AssignmentExpression {
+                                    left: m.node(78, 79, "a"),
+                                    operator: m.node(80, 81, AssignmentOperator::Plain),
+                                    right: m.node(82, 84, Primitive::RationalNumber(".5")),
+                                }),
+                            ]),
+                        }),
+                    }),
+                ]),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn leading_dot_rational_is_not_member_access() {
+        use error::Error;
+
+        // `x.5` lexes as `x` followed by the literal `.5`, there is no
+        // numeric member access to fall back on.
+        let errors = parse_err("contract Foo { function() { x.5; } }");
+
+        assert_eq!(errors, vec![Error {
+            token: Token::LiteralRational,
+            raw: ".5".into(),
+            span: 29..31,
+            since: None,
+        }]);
+    }
+
+    #[test]
+    fn trailing_dot_before_identifier_is_member_access() {
+        use parse;
+
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                function() {
+                    5.foo;
+                }
+            }
+
+        "#, [
+            m.node(14, 116, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 102, FunctionDefinition {
+                        name: None,
+                        params: NodeList::empty(),
+                        visibility: None,
+                        mutability: None,
+                        is_virtual: None,
+                        overrides: None,
+                        modifiers: NodeList::empty(),
+                        returns: NodeList::empty(),
+                        block: m.node(56, 102, Block {
+                            body: m.list([
+                                m.stmt_expr(78, 83, 84, MemberAccessExpression {
+                                    object: m.node(78, 79, Primitive::IntegerNumber("5", NumberUnit::None)),
+                                    member: m.node(80, 83, "foo"),
+                                }),
+                            ]),
+                        }),
+                    }),
+                ]),
+            }),
+        ]);
+
+        assert!(parse("contract Foo { function() { a = 5.; b = 5.e3; } }").is_ok());
+    }
+
+    #[test]
+    fn tuple_assignment_with_holes() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                function() {
+                    (a, , c) = f();
+                }
+            }
+
+        "#, [
+            m.node(14, 125, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 111, FunctionDefinition {
+                        name: None,
+                        params: NodeList::empty(),
+                        visibility: None,
+                        mutability: None,
+                        is_virtual: None,
+                        overrides: None,
+                        modifiers: NodeList::empty(),
+                        returns: NodeList::empty(),
+                        block: m.node(56, 111, Block {
+                            body: m.list([
+                                m.stmt_expr(78, 92, 93, AssignmentExpression {
+                                    left: m.node(78, 86, TupleExpression {
+                                        expressions: m.list([
+                                            m.node(79, 80, "a"),
+                                            None,
+                                            m.node(84, 85, "c"),
+                                        ]),
+                                    }),
+                                    operator: m.node(87, 88, AssignmentOperator::Plain),
+                                    right: m.node(89, 92, CallExpression {
+                                        callee: m.node(89, 90, "f"),
+                                        arguments: NodeList::empty(),
+                                    }),
+                                }),
+                            ]),
+                        }),
+                    }),
+                ]),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn new_array_expression() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                function() {
+                    new uint[](n + 1);
+                }
+            }
+
+        "#, [
+            m.node(14, 128, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 114, FunctionDefinition {
+                        name: None,
+                        params: NodeList::empty(),
+                        visibility: None,
+                        mutability: None,
+                        is_virtual: None,
+                        overrides: None,
+                        modifiers: NodeList::empty(),
+                        returns: NodeList::empty(),
+                        block: m.node(56, 114, Block {
+                            body: m.list([
+                                m.stmt_expr(78, 95, 96, CallExpression {
+                                    callee: m.node(78, 88, NewExpression {
+                                        type_name: m.node(82, 88, ArrayTypeName {
+                                            type_name: m.node(82, 86, ElementaryTypeName::Uint(32)),
+                                            length: None,
+                                        }),
+                                    }),
+                                    arguments: m.list([
+                                        m.node(89, 94, BinaryExpression {
+                                            left: m.node(89, 90, "n"),
+                                            operator: m.node(91, 92, BinaryOperator::Addition),
+                                            right: m.node(93, 94, Primitive::IntegerNumber("1", NumberUnit::None)),
+                                        }),
+                                    ]),
+                                }),
+                            ]),
+                        }),
+                    }),
+                ]),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn new_expression_with_call_options() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                function() {
+                    new Foo{salt: bytes32(0)}();
+                }
+            }
+
+        "#, [
+            m.node(14, 138, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 124, FunctionDefinition {
+                        name: None,
+                        params: NodeList::empty(),
+                        visibility: None,
+                        mutability: None,
+                        is_virtual: None,
+                        overrides: None,
+                        modifiers: NodeList::empty(),
+                        returns: NodeList::empty(),
+                        block: m.node(56, 124, Block {
+                            body: m.list([
+                                m.stmt_expr(78, 105, 106, CallExpression {
+                                    callee: m.node(78, 103, CallOptionsExpression {
+                                        callee: m.node(78, 85, NewExpression {
+                                            type_name: m.node(82, 85, "Foo"),
+                                        }),
+                                        options: m.list([
+                                            m.node(86, 102, NamedArgument {
+                                                name: m.node(86, 90, "salt"),
+                                                value: m.node(92, 102, CallExpression {
+                                                    callee: m.node(92, 99, ElementaryTypeName::Byte(32)),
+                                                    arguments: m.list([
+                                                        m.node(100, 101, Primitive::IntegerNumber("0", NumberUnit::None)),
+                                                    ]),
+                                                }),
+                                            }),
+                                        ]),
+                                    }),
+                                    arguments: NodeList::empty(),
+                                }),
+                            ]),
+                        }),
+                    }),
+                ]),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn string_concatenation() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                function() {
+                    "foo" "bar";
+                    hex"00ff" hex'dead';
+                }
+            }
+
+        "#, [
+            m.node(14, 163, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 149, FunctionDefinition {
+                        name: None,
+                        params: NodeList::empty(),
+                        visibility: None,
+                        mutability: None,
+                        is_virtual: None,
+                        overrides: None,
+                        modifiers: NodeList::empty(),
+                        returns: NodeList::empty(),
+                        block: m.node(56, 149, Block {
+                            body: m.list([
+                                m.stmt_expr(78, 89, 90, Primitive::StringConcatenation(m.list([
+                                    m.node(78, 83, "\"foo\""),
+                                    m.node(84, 89, "\"bar\""),
+                                ]))),
+                                m.stmt_expr(111, 130, 131, Primitive::HexStringConcatenation(m.list([
+                                    m.node(111, 120, "hex\"00ff\""),
+                                    m.node(121, 130, "hex'dead'"),
+                                ]))),
+                            ]),
+                        }),
+                    }),
+                ]),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn string_concatenation_across_lines() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                function() {
+                    "foo"
+                    // bar
+                    "bar";
+                }
+            }
+
+        "#, [
+            m.node(14, 169, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 155, FunctionDefinition {
+                        name: None,
+                        params: NodeList::empty(),
+                        visibility: None,
+                        mutability: None,
+                        is_virtual: None,
+                        overrides: None,
+                        modifiers: NodeList::empty(),
+                        returns: NodeList::empty(),
+                        block: m.node(56, 155, Block {
+                            body: m.list([
+                                m.stmt_expr(78, 136, 137, Primitive::StringConcatenation(m.list([
+                                    m.node(78, 83, "\"foo\""),
+                                    m.node(131, 136, "\"bar\""),
+                                ]))),
+                            ]),
+                        }),
+                    }),
+                ]),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn type_expression() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                function() {
+                    type(uint256).max;
+                    type(Foo).creationCode;
+                }
+            }
+
+        "#, [
+            m.node(14, 172, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 158, FunctionDefinition {
+                        name: None,
+                        params: NodeList::empty(),
+                        visibility: None,
+                        mutability: None,
+                        is_virtual: None,
+                        overrides: None,
+                        modifiers: NodeList::empty(),
+                        returns: NodeList::empty(),
+                        block: m.node(56, 158, Block {
+                            body: m.list([
+                                m.stmt_expr(78, 95, 96, MemberAccessExpression {
+                                    object: m.node(78, 91, TypeExpression {
+                                        type_name: m.node(83, 90, ElementaryTypeName::Uint(32)),
+                                    }),
+                                    member: m.node(92, 95, "max"),
+                                }),
+                                m.stmt_expr(117, 139, 140, MemberAccessExpression {
+                                    object: m.node(117, 126, TypeExpression {
+                                        type_name: m.node(122, 125, "Foo"),
+                                    }),
+                                    member: m.node(127, 139, "creationCode"),
+                                }),
+                            ]),
+                        }),
+                    }),
+                ]),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn elementary_type_conversions() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                function() {
+                    require(owner != address(0));
+                    owner = payable(x);
+                    uint8 small = uint8(y);
+                    address payable wallet = payable(0xdeadbeef);
+                }
+            }
+
+        "#, [
+            m.node(14, 289, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 275, FunctionDefinition {
+                        name: None,
+                        params: NodeList::empty(),
+                        visibility: None,
+                        mutability: None,
+                        is_virtual: None,
+                        overrides: None,
+                        modifiers: NodeList::empty(),
+                        returns: NodeList::empty(),
+                        block: m.node(56, 275, Block {
+                            body: m.list([
+                                m.stmt_expr(78, 106, 107, CallExpression {
+                                    callee: m.node(78, 85, "require"),
+                                    arguments: m.list([
+                                        m.node(86, 105, BinaryExpression {
+                                            left: m.node(86, 91, "owner"),
+                                            operator: m.node(92, 94, BinaryOperator::Inequality),
+                                            right: m.node(95, 105, CallExpression {
+                                                callee: m.node(95, 102, ElementaryTypeName::Address),
+                                                arguments: m.list([
+                                                    m.node(103, 104, Primitive::IntegerNumber("0", NumberUnit::None)),
+                                                ]),
+                                            }),
+                                        }),
+                                    ]),
+                                }),
+                                m.stmt_expr(128, 146, 147, AssignmentExpression {
+                                    left: m.node(128, 133, "owner"),
+                                    operator: m.node(134, 135, AssignmentOperator::Plain),
+                                    right: m.node(136, 146, CallExpression {
+                                        callee: m.node(136, 143, ElementaryTypeName::AddressPayable),
+                                        arguments: m.list([
+                                            m.node(144, 145, "x"),
+                                        ]),
+                                    }),
+                                }),
+                                m.node(168, 191, VariableDefinitionStatement {
+                                    declaration: m.node(168, 179, VariableDeclaration {
+                                        type_name: m.node(168, 173, ElementaryTypeName::Uint(1)),
+                                        location: None,
+                                        id: m.node(174, 179, "small"),
+                                    }),
+                                    init: m.node(182, 190, CallExpression {
+                                        callee: m.node(182, 187, ElementaryTypeName::Uint(1)),
+                                        arguments: m.list([
+                                            m.node(188, 189, "y"),
+                                        ]),
+                                    }),
+                                }),
+                                m.node(212, 257, VariableDefinitionStatement {
+                                    declaration: m.node(212, 234, VariableDeclaration {
+                                        type_name: m.node(212, 227, ElementaryTypeName::AddressPayable),
+                                        location: None,
+                                        id: m.node(228, 234, "wallet"),
+                                    }),
+                                    init: m.node(237, 256, CallExpression {
+                                        callee: m.node(237, 244, ElementaryTypeName::AddressPayable),
+                                        arguments: m.list([
+                                            m.node(245, 255, Primitive::HexNumber("0xdeadbeef")),
+                                        ]),
+                                    }),
+                                }),
+                            ]),
+                        }),
+                    }),
+                ]),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn units_only_follow_numbers() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                function() {
+                    2 ether;
+                    ether = 1;
+                }
+            }
+
+        "#, [
+            m.node(14, 149, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 135, FunctionDefinition {
+                        name: None,
+                        params: NodeList::empty(),
+                        visibility: None,
+                        mutability: None,
+                        is_virtual: None,
+                        overrides: None,
+                        modifiers: NodeList::empty(),
+                        returns: NodeList::empty(),
+                        block: m.node(56, 135, Block {
+                            body: m.list([
+                                m.stmt_expr(78, 85, 86, Primitive::IntegerNumber("2", NumberUnit::Ether(EtherUnit::Ether))),
+                                m.stmt_expr(107, 116, 117, AssignmentExpression {
+                                    left: m.node(107, 112, "ether"),
+                                    operator: m.node(113, 114, AssignmentOperator::Plain),
+                                    right: m.node(115, 116, Primitive::IntegerNumber("1", NumberUnit::None)),
+                                }),
+                            ]),
+                        }),
+                    }),
+                ]),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn units_on_scientific_notation() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                function() {
+                    1e3 ether;
+                    2.5e3 wei;
+                }
+            }
+
+        "#, [
+            m.node(14, 151, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 137, FunctionDefinition {
+                        name: None,
+                        params: NodeList::empty(),
+                        visibility: None,
+                        mutability: None,
+                        is_virtual: None,
+                        overrides: None,
+                        modifiers: NodeList::empty(),
+                        returns: NodeList::empty(),
+                        block: m.node(56, 137, Block {
+                            body: m.list([
+                                m.stmt_expr(78, 87, 88, Primitive::IntegerNumber("1e3", NumberUnit::Ether(EtherUnit::Ether))),
+                                m.stmt_expr(109, 118, 119, Primitive::IntegerNumber("2.5e3", NumberUnit::Ether(EtherUnit::Wei))),
+                            ]),
+                        }),
+                    }),
+                ]),
+            }),
+        ]);
+    }
 }
@@ -5,12 +5,42 @@ use {Parser, FunctionContext, RegularTypeNameContext};
 use lexer::Token;
 
 impl<'ast> Parser<'ast> {
-    pub fn function_definition(&mut self) -> Option<ContractPartNode<'ast>> {
+    /// `R` should be either `ContractPart` or `SourceUnit`
+    pub fn function_definition<R>(&mut self) -> Option<Node<'ast, R>>
+    where
+        R: From<FunctionDefinition<'ast>> + Copy,
+    {
+        let (start, end, function) = self.function();
+
+        self.node_at(start, end, function)
+    }
+
+    /// Free functions are defined outside of contracts and are always internal,
+    /// so any explicit visibility is an error.
+    pub fn free_function_definition(&mut self) -> Option<SourceUnitNode<'ast>> {
+        let (start, end, function) = self.function();
+
+        if let Some(visibility) = function.visibility {
+            let token = match visibility.value {
+                FunctionVisibility::External => Token::KeywordExternal,
+                FunctionVisibility::Public   => Token::KeywordPublic,
+                FunctionVisibility::Internal => Token::KeywordInternal,
+                FunctionVisibility::Private  => Token::KeywordPrivate,
+            };
+
+            self.error_at(token, visibility.start as usize..visibility.end as usize);
+        }
+
+        self.node_at(start, end, function)
+    }
+
+    fn function(&mut self) -> (u32, u32, FunctionDefinition<'ast>) {
         let start = self.start_then_advance();
 
+        // Legacy fallback functions are the only ones that can omit the name
         let name = match self.lexer.token {
-            Token::Identifier => self.str_node(),
-            _                 => None,
+            Token::ParenOpen => None,
+            _                => Some(self.expect_str_node(Token::Identifier)),
         };
 
         self.expect(Token::ParenOpen);
@@ -21,19 +51,116 @@ impl<'ast> Parser<'ast> {
 
         let mut mutability = None;
         let mut visibility = None;
+        let mut is_virtual = None;
+        let mut overrides  = None;
+
+        let modifiers    = self.function_attributes(&mut visibility, &mut mutability, &mut is_virtual, &mut overrides);
+        let returns      = self.function_returns();
+        let (end, block) = self.function_body();
+
+        (start, end, FunctionDefinition {
+            name,
+            params,
+            visibility,
+            mutability,
+            is_virtual,
+            overrides,
+            modifiers,
+            returns,
+            block,
+        })
+    }
+
+    pub fn special_function_definition(&mut self, kind: SpecialFunction) -> Option<ContractPartNode<'ast>> {
+        let start = self.start_then_advance();
+
+        self.expect(Token::ParenOpen);
+
+        let params = self.parameter_list();
+
+        self.expect(Token::ParenClose);
+
+        let mut mutability = None;
+        let mut visibility = None;
+        let mut is_virtual = None;
+        let mut overrides  = None;
+
+        let modifiers    = self.function_attributes(&mut visibility, &mut mutability, &mut is_virtual, &mut overrides);
+        let returns      = self.function_returns();
+        let (end, block) = self.function_body();
+
+        self.node_at(start, end, SpecialFunctionDefinition {
+            kind,
+            params,
+            visibility,
+            mutability,
+            is_virtual,
+            overrides,
+            modifiers,
+            returns,
+            block,
+        })
+    }
+
+    pub fn constructor_definition(&mut self) -> Option<ContractPartNode<'ast>> {
+        let start = self.start_then_advance();
+
+        self.expect(Token::ParenOpen);
+
+        let params = self.parameter_list();
+
+        self.expect(Token::ParenClose);
+
+        let mut mutability = None;
+        let mut visibility = None;
+        let mut is_virtual = None;
+        let mut overrides  = None;
+
+        let modifiers = self.function_attributes(&mut visibility, &mut mutability, &mut is_virtual, &mut overrides);
+
+        // Constructors are never inherited, so they can't be virtual or override
+        if let Some(flag) = is_virtual {
+            self.error_at(Token::Identifier, flag.start as usize..flag.end as usize);
+        }
+
+        if let Some(overrides) = overrides {
+            self.error_at(Token::Identifier, overrides.start as usize..overrides.end as usize);
+        }
+
+        let block = self.block::<FunctionContext, _>();
+
+        self.node_at(start, block.end, ConstructorDefinition {
+            params,
+            visibility,
+            mutability,
+            modifiers,
+            block,
+        })
+    }
+
+    fn function_attributes(
+        &mut self,
+        visibility: &mut Option<Node<'ast, FunctionVisibility>>,
+        mutability: &mut Option<Node<'ast, StateMutability>>,
+        is_virtual: &mut Option<FlagNode<'ast>>,
+        overrides: &mut Option<OverrideSpecifierNode<'ast>>,
+    ) -> ModifierInvocationList<'ast> {
         let modifiers = GrowableList::new();
 
         loop {
             match self.lexer.token {
-                Token::KeywordExternal => self.unique_flag(&mut visibility, FunctionVisibility::External),
-                Token::KeywordPublic   => self.unique_flag(&mut visibility, FunctionVisibility::Public),
-                Token::KeywordInternal => self.unique_flag(&mut visibility, FunctionVisibility::Internal),
-                Token::KeywordPrivate  => self.unique_flag(&mut visibility, FunctionVisibility::Private),
+                Token::KeywordExternal => self.unique_flag(visibility, FunctionVisibility::External),
+                Token::KeywordPublic   => self.unique_flag(visibility, FunctionVisibility::Public),
+                Token::KeywordInternal => self.unique_flag(visibility, FunctionVisibility::Internal),
+                Token::KeywordPrivate  => self.unique_flag(visibility, FunctionVisibility::Private),
+
+                Token::KeywordPure     => self.unique_flag(mutability, StateMutability::Pure),
+                Token::KeywordConstant => self.unique_flag(mutability, StateMutability::Constant),
+                Token::KeywordView     => self.unique_flag(mutability, StateMutability::View),
+                Token::KeywordPayable  => self.unique_flag(mutability, StateMutability::Payable),
 
-                Token::KeywordPure     => self.unique_flag(&mut mutability, StateMutability::Pure),
-                Token::KeywordConstant => self.unique_flag(&mut mutability, StateMutability::Constant),
-                Token::KeywordView     => self.unique_flag(&mut mutability, StateMutability::View),
-                Token::KeywordPayable  => self.unique_flag(&mut mutability, StateMutability::Payable),
+                Token::Identifier if self.lexer.slice() == "virtual"  => self.unique_flag(is_virtual, Flag),
+                Token::Identifier if self.lexer.slice() == "override" => self.unique_override(overrides),
 
                 _ => match self.modifier_invocation() {
                     Some(modifier) => modifiers.push(self.arena, modifier),
@@ -42,37 +169,32 @@ impl<'ast> Parser<'ast> {
             }
         }
 
-        let modifiers = modifiers.as_list();
-        let returns;
+        modifiers.as_list()
+    }
+
+    pub fn function_returns(&mut self) -> ParameterList<'ast> {
+        if !self.allow(Token::KeywordReturns) {
+            return NodeList::empty();
+        }
+
+        self.expect(Token::ParenOpen);
 
-        if self.allow(Token::KeywordReturns) {
-            self.expect(Token::ParenOpen);
+        let returns = self.parameter_list();
 
-            returns = self.parameter_list();
+        self.expect(Token::ParenClose);
 
-            self.expect(Token::ParenClose);
-        } else {
-            returns = NodeList::empty();
-        }
+        returns
+    }
 
-        let (end, block) = match self.lexer.token {
+    fn function_body(&mut self) -> (u32, Option<BlockNode<'ast>>) {
+        match self.lexer.token {
             Token::BraceOpen => {
                 let block = self.block::<FunctionContext, _>();
 
                 (block.end, Some(block))
             },
             _ => (self.expect_end(Token::Semicolon), None),
-        };
-
-        self.node_at(start, end, FunctionDefinition {
-            name,
-            params,
-            visibility,
-            mutability,
-            modifiers,
-            returns,
-            block,
-        })
+        }
     }
 
     fn modifier_invocation(&mut self) -> Option<Node<'ast, ModifierInvocation<'ast>>> {
@@ -82,10 +204,10 @@ impl<'ast> Parser<'ast> {
         let end;
 
         if self.allow(Token::ParenOpen) {
-            arguments = self.expression_list();
+            arguments = Some(self.expression_list());
             end       = self.expect_end(Token::ParenClose);
         } else {
-            arguments = NodeList::empty();
+            arguments = None;
             end       = id.end;
         };
 
@@ -115,12 +237,16 @@ impl<'ast> Parser<'ast> {
 
     fn parameter(&mut self) -> Option<Node<'ast, Parameter<'ast>>> {
         let type_name = self.type_name::<RegularTypeNameContext>()?;
+        let location  = self.storage_location();
         let name      = self.allow_str_node(Token::Identifier);
 
-        let end = name.end().unwrap_or_else(|| type_name.end);
+        let end = name.end()
+            .or_else(|| location.end())
+            .unwrap_or_else(|| type_name.end);
 
         self.node_at(type_name.start, end, Parameter {
             type_name,
+            location,
             name,
         })
     }
@@ -129,7 +255,7 @@ impl<'ast> Parser<'ast> {
 #[cfg(test)]
 mod test {
     use super::*;
-    use mock::{Mock, assert_units};
+    use mock::{Mock, assert_units, parse_err};
 
     #[test]
     fn empty_function() {
@@ -144,6 +270,7 @@ mod test {
 
         "#, [
             m.node(14, 102, ContractDefinition {
+                is_abstract: None,
                 name: m.node(23, 26, "Foo"),
                 inherits: NodeList::empty(),
                 body: m.list([
@@ -152,6 +279,8 @@ mod test {
                         params: NodeList::empty(),
                         visibility: None,
                         mutability: None,
+                        is_virtual: None,
+                        overrides: None,
                         modifiers: NodeList::empty(),
                         returns: NodeList::empty(),
                         block: None,
@@ -161,6 +290,8 @@ mod test {
                         params: NodeList::empty(),
                         visibility: None,
                         mutability: None,
+                        is_virtual: None,
+                        overrides: None,
                         modifiers: NodeList::empty(),
                         returns: NodeList::empty(),
                         block: None,
@@ -170,6 +301,45 @@ mod test {
         ]);
     }
 
+    #[test]
+    fn nameless_fallback_function() {
+        use parse;
+
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                function() public {}
+            }
+
+        "#, [
+            m.node(14, 79, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 65, FunctionDefinition {
+                        name: None,
+                        params: NodeList::empty(),
+                        visibility: m.node(56, 62, FunctionVisibility::Public),
+                        mutability: None,
+                        is_virtual: None,
+                        overrides: None,
+                        modifiers: NodeList::empty(),
+                        returns: NodeList::empty(),
+                        block: m.node(63, 65, Block {
+                            body: NodeList::empty(),
+                        }),
+                    }),
+                ]),
+            }),
+        ]);
+
+        assert!(parse("contract Foo { function 10() public {} }").is_err());
+        assert!(parse("contract Foo { function public() {} }").is_err());
+    }
+
     #[test]
     fn function_parameters() {
         let m = Mock::new();
@@ -182,6 +352,7 @@ mod test {
 
         "#, [
             m.node(14, 82, ContractDefinition {
+                is_abstract: None,
                 name: m.node(23, 26, "Foo"),
                 inherits: NodeList::empty(),
                 body: m.list([
@@ -190,15 +361,19 @@ mod test {
                         params: m.list([
                             m.node(54, 60, Parameter {
                                 type_name: m.node(54, 60, ElementaryTypeName::Uint(7)),
+                                location: None,
                                 name: None,
                             }),
                             m.node(62, 66, Parameter {
                                 type_name: m.node(62, 66, ElementaryTypeName::Bool),
+                                location: None,
                                 name: None,
                             }),
                         ]),
                         visibility: None,
                         mutability: None,
+                        is_virtual: None,
+                        overrides: None,
                         modifiers: NodeList::empty(),
                         returns: NodeList::empty(),
                         block: None,
@@ -208,6 +383,51 @@ mod test {
         ]);
     }
 
+    #[test]
+    fn named_function_with_unnamed_parameters() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                function f(uint, bool) {}
+            }
+
+        "#, [
+            m.node(14, 84, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 70, FunctionDefinition {
+                        name: m.node(54, 55, "f"),
+                        params: m.list([
+                            m.node(56, 60, Parameter {
+                                type_name: m.node(56, 60, ElementaryTypeName::Uint(32)),
+                                location: None,
+                                name: None,
+                            }),
+                            m.node(62, 66, Parameter {
+                                type_name: m.node(62, 66, ElementaryTypeName::Bool),
+                                location: None,
+                                name: None,
+                            }),
+                        ]),
+                        visibility: None,
+                        mutability: None,
+                        is_virtual: None,
+                        overrides: None,
+                        modifiers: NodeList::empty(),
+                        returns: NodeList::empty(),
+                        block: m.node(68, 70, Block {
+                            body: NodeList::empty(),
+                        }),
+                    }),
+                ]),
+            }),
+        ]);
+    }
+
     #[test]
     fn function_named_parameters() {
         let m = Mock::new();
@@ -220,6 +440,7 @@ mod test {
 
         "#, [
             m.node(14, 96, ContractDefinition {
+                is_abstract: None,
                 name: m.node(23, 26, "Foo"),
                 inherits: NodeList::empty(),
                 body: m.list([
@@ -228,15 +449,19 @@ mod test {
                         params: m.list([
                             m.node(59, 69, Parameter {
                                 type_name: m.node(59, 65, ElementaryTypeName::Uint(7)),
+                                location: None,
                                 name: m.node(66, 69, "wow"),
                             }),
                             m.node(71, 80, Parameter {
                                 type_name: m.node(71, 75, ElementaryTypeName::Bool),
+                                location: None,
                                 name: m.node(76, 80, "moon"),
                             }),
                         ]),
                         visibility: None,
                         mutability: None,
+                        is_virtual: None,
+                        overrides: None,
                         modifiers: NodeList::empty(),
                         returns: NodeList::empty(),
                         block: None,
@@ -258,6 +483,7 @@ mod test {
 
         "#, [
             m.node(14, 98, ContractDefinition {
+                is_abstract: None,
                 name: m.node(23, 26, "Foo"),
                 inherits: NodeList::empty(),
                 body: m.list([
@@ -266,14 +492,56 @@ mod test {
                         params: NodeList::empty(),
                         visibility: None,
                         mutability: None,
+                        is_virtual: None,
+                        overrides: None,
                         modifiers: NodeList::empty(),
                         returns: m.list([
                             m.node(70, 76, Parameter {
                                 type_name: m.node(70, 76, ElementaryTypeName::Uint(7)),
+                                location: None,
                                 name: None,
                             }),
                             m.node(78, 82, Parameter {
                                 type_name: m.node(78, 82, ElementaryTypeName::Bool),
+                                location: None,
+                                name: None,
+                            }),
+                        ]),
+                        block: None,
+                    }),
+                ]),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn bodiless_external_function_with_returns() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                function f() external returns (uint);
+            }
+
+        "#, [
+            m.node(14, 96, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 82, FunctionDefinition {
+                        name: m.node(54, 55, "f"),
+                        params: NodeList::empty(),
+                        visibility: m.node(58, 66, FunctionVisibility::External),
+                        mutability: None,
+                        is_virtual: None,
+                        overrides: None,
+                        modifiers: NodeList::empty(),
+                        returns: m.list([
+                            m.node(76, 80, Parameter {
+                                type_name: m.node(76, 80, ElementaryTypeName::Uint(32)),
+                                location: None,
                                 name: None,
                             }),
                         ]),
@@ -284,6 +552,183 @@ mod test {
         ]);
     }
 
+    #[test]
+    fn function_returns_named_and_unnamed() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                function a() returns (uint, bool);
+                function b() returns (uint a);
+            }
+
+        "#, [
+            m.node(14, 140, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 79, FunctionDefinition {
+                        name: m.node(54, 55, "a"),
+                        params: NodeList::empty(),
+                        visibility: None,
+                        mutability: None,
+                        is_virtual: None,
+                        overrides: None,
+                        modifiers: NodeList::empty(),
+                        returns: m.list([
+                            m.node(67, 71, Parameter {
+                                type_name: m.node(67, 71, ElementaryTypeName::Uint(32)),
+                                location: None,
+                                name: None,
+                            }),
+                            m.node(73, 77, Parameter {
+                                type_name: m.node(73, 77, ElementaryTypeName::Bool),
+                                location: None,
+                                name: None,
+                            }),
+                        ]),
+                        block: None,
+                    }),
+                    m.node(96, 126, FunctionDefinition {
+                        name: m.node(105, 106, "b"),
+                        params: NodeList::empty(),
+                        visibility: None,
+                        mutability: None,
+                        is_virtual: None,
+                        overrides: None,
+                        modifiers: NodeList::empty(),
+                        returns: m.list([
+                            m.node(118, 124, Parameter {
+                                type_name: m.node(118, 122, ElementaryTypeName::Uint(32)),
+                                location: None,
+                                name: m.node(123, 124, "a"),
+                            }),
+                        ]),
+                        block: None,
+                    }),
+                ]),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn function_returns_with_storage_location() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                function doge() returns (bytes memory data);
+                function moon() returns (uint[] calldata);
+            }
+
+        "#, [
+            m.node(14, 162, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 89, FunctionDefinition {
+                        name: m.node(54, 58, "doge"),
+                        params: NodeList::empty(),
+                        visibility: None,
+                        mutability: None,
+                        is_virtual: None,
+                        overrides: None,
+                        modifiers: NodeList::empty(),
+                        returns: m.list([
+                            m.node(70, 87, Parameter {
+                                type_name: m.node(70, 75, ElementaryTypeName::Bytes),
+                                location: m.node(76, 82, StorageLocation::Memory),
+                                name: m.node(83, 87, "data"),
+                            }),
+                        ]),
+                        block: None,
+                    }),
+                    m.node(106, 148, FunctionDefinition {
+                        name: m.node(115, 119, "moon"),
+                        params: NodeList::empty(),
+                        visibility: None,
+                        mutability: None,
+                        is_virtual: None,
+                        overrides: None,
+                        modifiers: NodeList::empty(),
+                        returns: m.list([
+                            m.node(131, 146, Parameter {
+                                type_name: m.node(131, 137, ArrayTypeName {
+                                    type_name: m.node(131, 135, ElementaryTypeName::Uint(32)),
+                                    length: None,
+                                }),
+                                location: m.node(138, 146, StorageLocation::Calldata),
+                                name: None,
+                            }),
+                        ]),
+                        block: None,
+                    }),
+                ]),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn function_returns_function_type() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                function getHandler() returns (function (uint) external returns (bool)) {}
+            }
+
+        "#, [
+            m.node(14, 133, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 119, FunctionDefinition {
+                        name: m.node(54, 64, "getHandler"),
+                        params: NodeList::empty(),
+                        visibility: None,
+                        mutability: None,
+                        is_virtual: None,
+                        overrides: None,
+                        modifiers: NodeList::empty(),
+                        returns: m.list([
+                            m.node(76, 115, Parameter {
+                                type_name: m.node(76, 115, FunctionTypeName {
+                                    params: m.list([
+                                        m.node(86, 90, Parameter {
+                                            type_name: m.node(86, 90, ElementaryTypeName::Uint(32)),
+                                            location: None,
+                                            name: None,
+                                        }),
+                                    ]),
+                                    visibility: m.node(92, 100, FunctionVisibility::External),
+                                    mutability: None,
+                                    returns: m.list([
+                                        m.node(110, 114, Parameter {
+                                            type_name: m.node(110, 114, ElementaryTypeName::Bool),
+                                            location: None,
+                                            name: None,
+                                        }),
+                                    ]),
+                                }),
+                                location: None,
+                                name: None,
+                            }),
+                        ]),
+                        block: m.node(117, 119, Block {
+                            body: NodeList::empty(),
+                        }),
+                    }),
+                ]),
+            }),
+        ]);
+    }
+
     #[test]
     fn function_mutability_and_visibility() {
         let m = Mock::new();
@@ -299,6 +744,7 @@ mod test {
 
         "#, [
             m.node(14, 217, ContractDefinition {
+                is_abstract: None,
                 name: m.node(23, 26, "Foo"),
                 inherits: NodeList::empty(),
                 body: m.list([
@@ -307,6 +753,8 @@ mod test {
                         params: NodeList::empty(),
                         visibility: m.node(65, 73, FunctionVisibility::External),
                         mutability: m.node(60, 64, StateMutability::Pure),
+                        is_virtual: None,
+                        overrides: None,
                         modifiers: NodeList::empty(),
                         returns: NodeList::empty(),
                         block: None,
@@ -316,6 +764,8 @@ mod test {
                         params: NodeList::empty(),
                         visibility: m.node(107, 115, FunctionVisibility::Internal),
                         mutability: m.node(116, 120, StateMutability::View),
+                        is_virtual: None,
+                        overrides: None,
                         modifiers: NodeList::empty(),
                         returns: NodeList::empty(),
                         block: None,
@@ -325,6 +775,8 @@ mod test {
                         params: NodeList::empty(),
                         visibility: m.node(154, 161, FunctionVisibility::Private),
                         mutability: None,
+                        is_virtual: None,
+                        overrides: None,
                         modifiers: NodeList::empty(),
                         returns: NodeList::empty(),
                         block: None,
@@ -334,6 +786,8 @@ mod test {
                         params: NodeList::empty(),
                         visibility: None,
                         mutability: m.node(195, 202, StateMutability::Payable),
+                        is_virtual: None,
+                        overrides: None,
                         modifiers: NodeList::empty(),
                         returns: NodeList::empty(),
                         block: None,
@@ -355,6 +809,7 @@ mod test {
 
         "#, [
             m.node(14, 96, ContractDefinition {
+                is_abstract: None,
                 name: m.node(23, 26, "Foo"),
                 inherits: NodeList::empty(),
                 body: m.list([
@@ -363,18 +818,20 @@ mod test {
                         params: NodeList::empty(),
                         visibility: None,
                         mutability: m.node(72, 76, StateMutability::Pure),
+                        is_virtual: None,
+                        overrides: None,
                         modifiers: m.list([
                             m.node(56, 66, ModifierInvocation {
                                 id: m.node(56, 66, "only_doges"),
-                                arguments: NodeList::empty(),
+                                arguments: None,
                             }),
                             m.node(67, 71, ModifierInvocation {
                                 id: m.node(67, 71, "such"),
-                                arguments: NodeList::empty(),
+                                arguments: None,
                             }),
                             m.node(77, 81, ModifierInvocation {
                                 id: m.node(77, 81, "moon"),
-                                arguments: NodeList::empty(),
+                                arguments: None,
                             }),
                         ]),
                         returns: NodeList::empty(),
@@ -397,6 +854,7 @@ mod test {
 
         "#, [
             m.node(14, 102, ContractDefinition {
+                is_abstract: None,
                 name: m.node(23, 26, "Foo"),
                 inherits: NodeList::empty(),
                 body: m.list([
@@ -405,17 +863,19 @@ mod test {
                         params: NodeList::empty(),
                         visibility: None,
                         mutability: m.node(83, 87, StateMutability::Pure),
+                        is_virtual: None,
+                        overrides: None,
                         modifiers: m.list([
                             m.node(56, 75, ModifierInvocation {
                                 id: m.node(56, 60, "only"),
-                                arguments: m.list([
+                                arguments: Some(m.list([
                                     m.node(61, 65, "moon"),
                                     m.node(67, 74, Primitive::String("\"doges\"")),
-                                ]),
+                                ])),
                             }),
                             m.node(76, 82, ModifierInvocation {
                                 id: m.node(76, 80, "such"),
-                                arguments: NodeList::empty(),
+                                arguments: Some(NodeList::empty()),
                             }),
                         ]),
                         returns: NodeList::empty(),
@@ -426,6 +886,231 @@ mod test {
         ]);
     }
 
+    #[test]
+    fn modifier_invocation_with_and_without_parentheses() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                function f() onlyOwner onlyOwner() {}
+            }
+
+        "#, [
+            m.node(14, 96, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 82, FunctionDefinition {
+                        name: m.node(54, 55, "f"),
+                        params: NodeList::empty(),
+                        visibility: None,
+                        mutability: None,
+                        is_virtual: None,
+                        overrides: None,
+                        modifiers: m.list([
+                            m.node(58, 67, ModifierInvocation {
+                                id: m.node(58, 67, "onlyOwner"),
+                                arguments: None,
+                            }),
+                            m.node(68, 79, ModifierInvocation {
+                                id: m.node(68, 77, "onlyOwner"),
+                                arguments: Some(NodeList::empty()),
+                            }),
+                        ]),
+                        returns: NodeList::empty(),
+                        block: m.node(80, 82, Block {
+                            body: NodeList::empty(),
+                        }),
+                    }),
+                ]),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn modifiers_must_precede_returns() {
+        use parse;
+
+        assert!(parse("contract Foo { function f() onlyOwner returns (uint) {} }").is_ok());
+
+        let errors = parse_err("contract Foo { function f() returns (uint) onlyOwner {} }");
+
+        assert_eq!(errors[0].token, Token::Identifier);
+        assert_eq!(&*errors[0].raw, "onlyOwner");
+        assert_eq!(errors[0].span, 43..52);
+    }
+
+    #[test]
+    fn constructor_definition() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                constructor() public {}
+                constructor(uint8 bar) internal payable only {}
+                constructor() {}
+            }
+
+        "#, [
+            m.node(14, 179, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 68, ConstructorDefinition {
+                        params: NodeList::empty(),
+                        visibility: m.node(59, 65, FunctionVisibility::Public),
+                        mutability: None,
+                        modifiers: NodeList::empty(),
+                        block: m.node(66, 68, Block {
+                            body: NodeList::empty(),
+                        }),
+                    }),
+                    m.node(85, 132, ConstructorDefinition {
+                        params: m.list([
+                            m.node(97, 106, Parameter {
+                                type_name: m.node(97, 102, ElementaryTypeName::Uint(1)),
+                                location: None,
+                                name: m.node(103, 106, "bar"),
+                            }),
+                        ]),
+                        visibility: m.node(108, 116, FunctionVisibility::Internal),
+                        mutability: m.node(117, 124, StateMutability::Payable),
+                        modifiers: m.list([
+                            m.node(125, 129, ModifierInvocation {
+                                id: m.node(125, 129, "only"),
+                                arguments: None,
+                            }),
+                        ]),
+                        block: m.node(130, 132, Block {
+                            body: NodeList::empty(),
+                        }),
+                    }),
+                    m.node(149, 165, ConstructorDefinition {
+                        params: NodeList::empty(),
+                        visibility: None,
+                        mutability: None,
+                        modifiers: NodeList::empty(),
+                        block: m.node(163, 165, Block {
+                            body: NodeList::empty(),
+                        }),
+                    }),
+                ]),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn constructor_with_base_call() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo is Base {
+                constructor(uint x) Base(x) {}
+            }
+
+        "#, [
+            m.node(14, 97, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: m.list([
+                    m.node(30, 34, InheritanceSpecifier {
+                        name: m.node(30, 34, "Base"),
+                        arguments: NodeList::empty(),
+                    }),
+                ]),
+                body: m.list([
+                    m.node(53, 83, ConstructorDefinition {
+                        params: m.list([
+                            m.node(65, 71, Parameter {
+                                type_name: m.node(65, 69, ElementaryTypeName::Uint(32)),
+                                location: None,
+                                name: m.node(70, 71, "x"),
+                            }),
+                        ]),
+                        visibility: None,
+                        mutability: None,
+                        modifiers: m.list([
+                            m.node(73, 80, ModifierInvocation {
+                                id: m.node(73, 77, "Base"),
+                                arguments: Some(m.list([
+                                    m.node(78, 79, "x"),
+                                ])),
+                            }),
+                        ]),
+                        block: m.node(81, 83, Block {
+                            body: NodeList::empty(),
+                        }),
+                    }),
+                ]),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn special_function_definition() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                receive() external payable onlyOwner {}
+                fallback() external payable logged("fallback") {}
+            }
+
+        "#, [
+            m.node(14, 164, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 84, SpecialFunctionDefinition {
+                        kind: SpecialFunction::Receive,
+                        params: NodeList::empty(),
+                        visibility: m.node(55, 63, FunctionVisibility::External),
+                        mutability: m.node(64, 71, StateMutability::Payable),
+                        is_virtual: None,
+                        overrides: None,
+                        modifiers: m.list([
+                            m.node(72, 81, ModifierInvocation {
+                                id: m.node(72, 81, "onlyOwner"),
+                                arguments: None,
+                            }),
+                        ]),
+                        returns: NodeList::empty(),
+                        block: m.node(82, 84, Block {
+                            body: NodeList::empty(),
+                        }),
+                    }),
+                    m.node(101, 150, SpecialFunctionDefinition {
+                        kind: SpecialFunction::Fallback,
+                        params: NodeList::empty(),
+                        visibility: m.node(112, 120, FunctionVisibility::External),
+                        mutability: m.node(121, 128, StateMutability::Payable),
+                        is_virtual: None,
+                        overrides: None,
+                        modifiers: m.list([
+                            m.node(129, 147, ModifierInvocation {
+                                id: m.node(129, 135, "logged"),
+                                arguments: Some(m.list([
+                                    m.node(136, 146, Primitive::String("\"fallback\"")),
+                                ])),
+                            }),
+                        ]),
+                        returns: NodeList::empty(),
+                        block: m.node(148, 150, Block {
+                            body: NodeList::empty(),
+                        }),
+                    }),
+                ]),
+            }),
+        ]);
+    }
+
     #[test]
     fn function_flags_are_unique_per_kind() {
         use parse;
@@ -436,4 +1121,104 @@ mod test {
         assert!(parse("contract Foo { function() internal external; }").is_err());
         assert!(parse("contract Foo { function() payable constant; }").is_err());
     }
+
+    #[test]
+    fn duplicate_mutability_points_at_repeated_keyword() {
+        use error::Error;
+
+        let errors = parse_err("contract Foo { function f() view view {} }");
+
+        assert_eq!(errors, vec![Error {
+            token: Token::KeywordView,
+            raw: "view".into(),
+            span: 33..37,
+            since: None,
+        }]);
+    }
+
+    #[test]
+    fn function_with_everything() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                function transfer(address to, uint256 amount) public virtual override onlyOwner returns (bool success) {
+                    return true;
+                }
+            }
+
+        "#, [
+            m.node(14, 214, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 200, FunctionDefinition {
+                        name: m.node(54, 62, "transfer"),
+                        params: m.list([
+                            m.node(63, 73, Parameter {
+                                type_name: m.node(63, 70, ElementaryTypeName::Address),
+                                location: None,
+                                name: m.node(71, 73, "to"),
+                            }),
+                            m.node(75, 89, Parameter {
+                                type_name: m.node(75, 82, ElementaryTypeName::Uint(32)),
+                                location: None,
+                                name: m.node(83, 89, "amount"),
+                            }),
+                        ]),
+                        visibility: m.node(91, 97, FunctionVisibility::Public),
+                        mutability: None,
+                        is_virtual: m.node(98, 105, Flag),
+                        overrides: m.node(106, 114, OverrideSpecifier {
+                            bases: NodeList::empty(),
+                        }),
+                        modifiers: m.list([
+                            m.node(115, 124, ModifierInvocation {
+                                id: m.node(115, 124, "onlyOwner"),
+                                arguments: None,
+                            }),
+                        ]),
+                        returns: m.list([
+                            m.node(134, 146, Parameter {
+                                type_name: m.node(134, 138, ElementaryTypeName::Bool),
+                                location: None,
+                                name: m.node(139, 146, "success"),
+                            }),
+                        ]),
+                        block: m.node(148, 200, Block {
+                            body: m.list([
+                                m.node(170, 182, ReturnStatement {
+                                    value: m.node(177, 181, Primitive::Bool(true)),
+                                }),
+                            ]),
+                        }),
+                    }),
+                ]),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn constructor_cannot_be_virtual_or_override() {
+        use error::Error;
+
+        let errors = parse_err("contract Foo { constructor() virtual override(A) {} }");
+
+        assert_eq!(errors, vec![
+            Error {
+                token: Token::Identifier,
+                raw: "virtual".into(),
+                span: 29..36,
+                since: None,
+            },
+            Error {
+                token: Token::Identifier,
+                raw: "override(A)".into(),
+                span: 37..48,
+                since: None,
+            },
+        ]);
+    }
 }
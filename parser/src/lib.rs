@@ -1,4 +1,5 @@
 extern crate toolshed;
+extern crate tiny_keccak;
 extern crate lunarity_lexer as lexer;
 extern crate lunarity_ast as ast;
 
@@ -19,45 +20,136 @@ mod nested;
 mod statement;
 mod assembly;
 mod error;
+mod comments;
+mod checksum;
+mod tokens;
+mod version;
 
-use toolshed::{Arena, NulTermStr};
+use std::ops::Range;
+
+use toolshed::Arena;
 use toolshed::list::GrowableList;
 
 pub use self::statement::{StatementContext, FunctionContext, ModifierContext};
 pub use self::type_name::{TypeNameContext, RegularTypeNameContext, StatementTypeNameContext};
 pub use self::nested::*;
+pub use self::version::Version;
 
 use ast::*;
 use error::Error;
-use lexer::{Lexer, Token};
+use lexer::{Token, Source};
 use lexer::Token::*;
+use tokens::Tokens;
 
 
 pub struct Parser<'ast> {
     arena: &'ast Arena,
 
-    /// Lexer will produce tokens from the source
-    lexer: Lexer<NulTermStr<'ast>>,
+    /// Tokens from the source, either lexed on the fly or read from a buffer
+    lexer: Tokens<'ast>,
 
     /// Errors occurred during parsing
     errors: Vec<Error>,
 
+    /// Warnings found during parsing
+    warnings: Vec<Warning>,
+
     /// AST under construction
     body: SourceUnitList<'ast>,
+
+    /// Current nesting depth of expressions
+    depth: usize,
+
+    /// Nesting depth beyond which expressions produce an error
+    max_depth: usize,
+
+    /// Solidity version to parse for, `None` accepts all supported syntax
+    target: Option<Version>,
+
+    /// Whether to check address literals against their EIP-55 checksums
+    checksums: bool,
 }
 
+/// Default limit on how deeply expressions can be nested.
+pub const DEFAULT_MAX_DEPTH: usize = 256;
+
+/// Rough length of source per token, somewhat below the ~10 bytes common
+/// in contracts, so that the buffer of tokens rarely has to grow.
+const BYTES_PER_TOKEN: usize = 8;
+
 impl<'ast> Parser<'ast> {
     pub fn new(source: &str, arena: &'ast Arena) -> Self {
         let source = arena.alloc_nul_term_str(source);
 
         Parser {
             arena,
-            lexer: Lexer::new(source),
+            lexer: Tokens::new(source),
             errors: Vec::new(),
+            warnings: Vec::new(),
             body: NodeList::empty(),
+            depth: 0,
+            max_depth: DEFAULT_MAX_DEPTH,
+            target: None,
+            checksums: false,
         }
     }
 
+    /// Same as `new`, but lexes the entire source into a flat buffer of
+    /// tokens before any parsing happens. The buffer is sized up front
+    /// from the length of the source.
+    pub fn buffered(source: &str, arena: &'ast Arena) -> Self {
+        Parser::with_capacity(source, arena, source.len() / BYTES_PER_TOKEN)
+    }
+
+    /// Same as `buffered`, but with room for exactly `capacity` tokens.
+    ///
+    /// The `Arena` itself grows in fixed pages and can't be reserved,
+    /// node lists are allocated on it as they are built, so the buffer of
+    /// tokens is the only storage that can be sized ahead.
+    pub fn with_capacity(source: &str, arena: &'ast Arena, capacity: usize) -> Self {
+        let source = arena.alloc_nul_term_str(source);
+
+        Parser {
+            arena,
+            lexer: Tokens::buffered(source, capacity),
+            errors: Vec::new(),
+            warnings: Vec::new(),
+            body: NodeList::empty(),
+            depth: 0,
+            max_depth: DEFAULT_MAX_DEPTH,
+            target: None,
+            checksums: false,
+        }
+    }
+
+    /// Limit how deeply expressions can be nested. Past the limit the parser
+    /// produces an error instead of recursing further and overflowing the stack.
+    pub fn max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Parse for a specific Solidity version, rejecting syntax introduced
+    /// after it. By default all supported syntax is accepted.
+    pub fn target(mut self, version: Version) -> Self {
+        self.target = Some(version);
+        self
+    }
+
+    /// Collect all comments found in the source, available via
+    /// `Program::comments` on the parsed program.
+    pub fn comments(mut self) -> Self {
+        self.lexer.collect_comments();
+        self
+    }
+
+    /// Check the casing of address literals against their EIP-55 checksums,
+    /// mismatches are available via `Program::warnings` on the parsed program.
+    pub fn checksums(mut self) -> Self {
+        self.checksums = true;
+        self
+    }
+
     #[inline]
     fn allow(&mut self, token: Token) -> bool {
         if self.lexer.token == token {
@@ -167,9 +259,40 @@ impl<'ast> Parser<'ast> {
             token,
             raw,
             span,
+            since: None,
+        });
+    }
+
+    /// Report an error at `span` of an already consumed `token`.
+    fn error_at(&mut self, token: Token, span: Range<usize>) {
+        let raw = self.lexer.source.slice(span.clone()).unwrap_or("").into();
+
+        self.errors.push(Error {
+            token,
+            raw,
+            span,
+            since: None,
         });
     }
 
+    /// Report an error at `span` if the targeted Solidity version is older
+    /// than `since`, the version in which the syntax there was introduced.
+    fn require_version(&mut self, since: Version, token: Token, span: Range<usize>) {
+        match self.target {
+            Some(target) if target < since => {
+                let raw = self.lexer.source.slice(span.clone()).unwrap_or("").into();
+
+                self.errors.push(Error {
+                    token,
+                    raw,
+                    span,
+                    since: Some(since),
+                });
+            },
+            _ => {},
+        }
+    }
+
     #[inline]
     fn alloc<T>(&mut self, val: NodeInner<T>) -> Node<'ast, T>
     where
@@ -237,10 +360,11 @@ impl<'ast> Parser<'ast> {
         F: Copy,
     {
         if at.is_some() {
-            self.lexer.advance();
-
             // TODO: More descriptive errors, something like "Can't redeclare visibility/mutability"
-            return self.error();
+            self.error();
+
+            // Point the error at the repeated keyword, but skip it to keep parsing
+            return self.lexer.advance();
         }
 
         *at = self.node_at_token(flag);
@@ -248,27 +372,64 @@ impl<'ast> Parser<'ast> {
 }
 
 /// Parse the Solidity source from `&str` and produce an Abstract Syntax Tree for it.
-pub fn parse<'src, 'ast>(source: &'src str) -> Result<Program<'ast>, Vec<Error>> {
+pub fn parse<'ast>(source: &str) -> Result<Program<'ast>, Vec<Error>> {
+    parse_using(source, |source, arena| Parser::new(source, arena))
+}
+
+/// Same as `parse`, but the entire source is lexed into a flat buffer of
+/// tokens first, and the parser then reads from that buffer.
+pub fn parse_buffered<'ast>(source: &str) -> Result<Program<'ast>, Vec<Error>> {
+    parse_using(source, |source, arena| Parser::buffered(source, arena))
+}
+
+/// Same as `parse`, but with the parser created by `new`, such as
+/// `Parser::with_capacity` with a capacity known ahead.
+pub fn parse_using<'src, 'ast, F>(source: &'src str, new: F) -> Result<Program<'ast>, Vec<Error>>
+where
+    F: for<'arena> FnOnce(&'src str, &'arena Arena) -> Parser<'arena>,
+{
     let arena = Arena::new();
 
-    let (body, errors) = {
-        let mut parser = Parser::new(source, &arena);
+    let (body, errors, comments, warnings) = {
+        let mut parser = new(source, &arena);
 
         parser.parse();
 
-        (parser.body.into_unsafe(), parser.errors)
+        let comments = parser.lexer.take_comments();
+
+        (parser.body.into_unsafe(), parser.errors, comments, parser.warnings)
     };
 
     match errors.len() {
-        0 => Ok(Program::new(body, arena)),
+        0 => Ok(Program::new(body, arena).with_comments(comments).with_warnings(warnings)),
         _ => Err(errors)
     }
 }
 
+/// Same as `parse`, but the `Parser` is first passed through `configure`
+/// to set any options, which can be freely combined:
+///
+/// ```ignore
+/// parse_with(source, |parser| parser.target(Version::new(0, 7, 6)).comments());
+/// ```
+pub fn parse_with<'ast, F>(source: &str, configure: F) -> Result<Program<'ast>, Vec<Error>>
+where
+    F: for<'arena> FnOnce(Parser<'arena>) -> Parser<'arena>,
+{
+    parse_using(source, |source, arena| configure(Parser::new(source, arena)))
+}
+
+/// Same as `parse`, but converts the result into an owned summary of the
+/// declarations that doesn't depend on the `Arena`, see `ast::summary`.
+pub fn parse_summary(source: &str) -> Result<summary::Program, Vec<Error>> {
+    parse(source).map(|program| summary::Program::from(&program))
+}
+
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use mock::parse_err;
 
     #[test]
     fn can_parse_second_price_auction() {
@@ -276,4 +437,164 @@ mod test {
 
         parse(source).unwrap();
     }
+
+    #[test]
+    fn deeply_nested_expression() {
+        let source = format!("contract Foo {{ function() {{ {}x{}; }} }}", "(".repeat(10000), ")".repeat(10000));
+
+        let errors = parse_err(&source);
+
+        // The first error is at the parenthesis past the limit
+        let offset = 28 + DEFAULT_MAX_DEPTH;
+
+        assert_eq!(errors[0], Error {
+            token: ParenOpen,
+            raw: "(".into(),
+            span: offset..offset + 1,
+            since: None,
+        });
+
+        let source = "contract Foo { function() { ((((x)))); } }";
+
+        assert!(parse(source).is_ok());
+        assert!(parse_with(source, |parser| parser.max_depth(5)).is_ok());
+        assert!(parse_with(source, |parser| parser.max_depth(4)).is_err());
+    }
+
+    #[test]
+    fn options_combine() {
+        let source = "contract Foo { function() { unchecked { x; } } } // done";
+
+        assert!(parse_with(source, |parser| parser.target(Version::new(0, 7, 6)).comments()).is_err());
+
+        let program = parse_with(source, |parser| parser.target(Version::new(0, 8, 0)).comments()).unwrap();
+
+        assert_eq!(program.comments().len(), 1);
+    }
+
+    #[test]
+    fn node_accessors() {
+        let program = parse("contract Foo { struct Bar { uint baz; } }").unwrap();
+
+        let contract = match program.body().iter().next().unwrap().value {
+            SourceUnit::ContractDefinition(contract) => contract,
+            unit => panic!("Expected a contract, got {:?}", unit),
+        };
+
+        let structure = match contract.body.iter().next().unwrap().value {
+            ContractPart::StructDefinition(structure) => structure,
+            part => panic!("Expected a struct, got {:?}", part),
+        };
+
+        let member = structure.body.iter().next().unwrap();
+
+        assert_eq!(member.span(), 28..36);
+        assert_eq!(member.id.span(), 33..36);
+        assert_eq!(member.id.value, "baz");
+    }
+
+    #[test]
+    fn buffered_tokens_produce_identical_ast() {
+        let source = include_str!("../../lunarity/benches/second-price-auction.sol");
+
+        let streamed = parse(source).unwrap();
+        let buffered = parse_buffered(source).unwrap();
+
+        for (streamed, buffered) in streamed.body().iter().zip(buffered.body().iter()) {
+            assert_eq!(streamed, buffered);
+        }
+
+        assert_eq!(streamed.body().iter().count(), buffered.body().iter().count());
+    }
+
+    #[test]
+    fn buffer_capacity_is_only_a_hint() {
+        let source = include_str!("../../lunarity/benches/second-price-auction.sol");
+
+        let presized = parse_buffered(source).unwrap();
+        let undersized = parse_using(source, |source, arena| Parser::with_capacity(source, arena, 1)).unwrap();
+
+        for (presized, undersized) in presized.body().iter().zip(undersized.body().iter()) {
+            assert_eq!(presized, undersized);
+        }
+
+        assert_eq!(presized.body().iter().count(), undersized.body().iter().count());
+    }
+
+    #[test]
+    fn can_parse_to_summary() {
+        use ast::summary;
+
+        let program = parse_summary(r#"
+
+            contract Foo is Bar {
+                uint256 constant total = 10;
+                event Transfer(address indexed from, uint256);
+            }
+
+        "#).unwrap();
+
+        assert_eq!(program.body.len(), 1);
+
+        let contract = match program.body[0].value {
+            summary::SourceUnit::ContractDefinition(ref contract) => contract,
+            ref unit => panic!("Expected a contract, got {:?}", unit),
+        };
+
+        assert_eq!(contract.name.value, "Foo");
+        assert_eq!(contract.inherits[0].value.name.value, "Bar");
+        assert_eq!(contract.body, vec![
+            summary::Node {
+                start: 52,
+                end: 80,
+                value: summary::ContractPart::StateVariableDeclaration(summary::StateVariableDeclaration {
+                    type_name: summary::Node {
+                        start: 52,
+                        end: 59,
+                        value: summary::TypeName::ElementaryTypeName(ElementaryTypeName::Uint(32)),
+                    },
+                    visibility: None,
+                    constant: Some(summary::Node { start: 60, end: 68, value: Flag }),
+                    name: summary::Node { start: 69, end: 74, value: "total".into() },
+                    init: Some(summary::Node { start: 77, end: 79, value: () }),
+                }),
+            },
+            summary::Node {
+                start: 97,
+                end: 143,
+                value: summary::ContractPart::EventDefinition(summary::EventDefinition {
+                    anonymous: None,
+                    name: summary::Node { start: 103, end: 111, value: "Transfer".into() },
+                    params: vec![
+                        summary::Node {
+                            start: 112,
+                            end: 132,
+                            value: summary::IndexedParameter {
+                                type_name: summary::Node {
+                                    start: 112,
+                                    end: 119,
+                                    value: summary::TypeName::ElementaryTypeName(ElementaryTypeName::Address),
+                                },
+                                indexed: Some(summary::Node { start: 120, end: 127, value: Flag }),
+                                name: Some(summary::Node { start: 128, end: 132, value: "from".into() }),
+                            },
+                        },
+                        summary::Node {
+                            start: 134,
+                            end: 141,
+                            value: summary::IndexedParameter {
+                                type_name: summary::Node {
+                                    start: 134,
+                                    end: 141,
+                                    value: summary::TypeName::ElementaryTypeName(ElementaryTypeName::Uint(32)),
+                                },
+                                indexed: None,
+                                name: None,
+                            },
+                        },
+                    ],
+                }),
+            },
+        ]);
+    }
 }
@@ -2,6 +2,8 @@ use toolshed::Arena;
 use toolshed::list::List;
 
 use ast::*;
+use error::Error;
+use Parser;
 
 pub struct Mock {
     arena: Arena
@@ -31,10 +33,10 @@ impl Mock {
     {
         let expression = Node::new(self.arena.alloc(NodeInner::new(start, e_end, val.into())));
 
-        Node::new(self.arena.alloc(NodeInner::new(start, s_end, expression.into()))).into()
+        Node::new(self.arena.alloc(NodeInner::<Statement>::new(start, s_end, expression.into()))).into()
     }
 
-    pub fn list<'mock, T, L>(&'mock self, list: L) -> List<T> where
+    pub fn list<'mock, T, L>(&'mock self, list: L) -> List<'mock, T> where
         T: 'mock + Copy,
         L: AsRef<[T]>,
     {
@@ -65,3 +67,21 @@ where
 
     assert_eq!(got, expected, "Expected {} units, got {}", expected, got);
 }
+
+/// Parse the source, expecting it to fail, and return the errors.
+pub fn parse_err(source: &str) -> Vec<Error> {
+    parse_err_with(source, |parser| parser)
+}
+
+/// Same as `parse_err`, but with the `Parser` configured as in `parse_with`.
+pub fn parse_err_with<F>(source: &str, configure: F) -> Vec<Error>
+where
+    F: for<'arena> FnOnce(Parser<'arena>) -> Parser<'arena>,
+{
+    use parse_with;
+
+    match parse_with(source, configure) {
+        Err(errors) => errors,
+        Ok(_)       => panic!("Expected an error"),
+    }
+}
@@ -1,5 +1,7 @@
+use toolshed::list::ListBuilder;
+
 use Parser;
-use lexer::{Token, Logos, lookup};
+use lexer::{Token, Logos, Source, lookup};
 use ast::*;
 
 #[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
@@ -28,43 +30,46 @@ pub const P2: Precedence = Precedence(2);
 const INVALID: Precedence = Precedence(100);
 
 static NESTED_LUT: [NestedHandler; Token::SIZE] = lookup! {
-    Token::Accessor               => NestedHandler(P2, MEMBER),
-    Token::ParenOpen              => NestedHandler(P2, CALL),
-    Token::BracketOpen            => NestedHandler(P2, INDEX),
-    Token::OperatorIncrement      => NestedHandler(P2, INC),
-    Token::OperatorDecrement      => NestedHandler(P2, DEC),
-    Token::OperatorExponent       => NestedHandler(P3, EXPONENT),
-    Token::OperatorMultiplication => NestedHandler(P4, MUL),
-    Token::OperatorDivision       => NestedHandler(P4, DIV),
-    Token::OperatorRemainder      => NestedHandler(P4, REMAINDER),
-    Token::OperatorAddition       => NestedHandler(P5, ADD),
-    Token::OperatorSubtraction    => NestedHandler(P5, SUB),
-    Token::OperatorBitShiftLeft   => NestedHandler(P6, BIT_SHIFT_LEFT),
-    Token::OperatorBitShiftRight  => NestedHandler(P6, BIT_SHIFT_RIGHT),
-    Token::OperatorBitAnd         => NestedHandler(P7, BIT_AND),
-    Token::OperatorBitXor         => NestedHandler(P8, BIT_XOR),
-    Token::OperatorBitOr          => NestedHandler(P9, BIT_OR),
-    Token::OperatorLesser         => NestedHandler(P10, LESSER),
-    Token::OperatorLesserEquals   => NestedHandler(P10, LESSER_EQUALITY),
-    Token::OperatorGreater        => NestedHandler(P10, GREATER),
-    Token::OperatorGreaterEquals  => NestedHandler(P10, GREATER_EQUALITY),
-    Token::OperatorEquality       => NestedHandler(P11, EQUALITY),
-    Token::OperatorInequality     => NestedHandler(P11, INEQUALITY),
-    Token::OperatorLogicalAnd     => NestedHandler(P12, LOGICAL_AND),
-    Token::OperatorLogicalOr      => NestedHandler(P13, LOGICAL_OR),
-    Token::OperatorConditional    => NestedHandler(P14, CONDITIONAL),
-    Token::Assign                 => NestedHandler(TOP, ASSIGN),
-    Token::AssignAddition         => NestedHandler(TOP, ASSIGN_ADD),
-    Token::AssignSubtraction      => NestedHandler(TOP, ASSIGN_SUB),
-    Token::AssignMultiplication   => NestedHandler(TOP, ASSIGN_MUL),
-    Token::AssignDivision         => NestedHandler(TOP, ASSIGN_DIV),
-    Token::AssignRemainder        => NestedHandler(TOP, ASSIGN_REM),
-    Token::AssignBitShiftLeft     => NestedHandler(TOP, ASSIGN_BIT_SHIFT_LEFT),
-    Token::AssignBitShiftRight    => NestedHandler(TOP, ASSIGN_BIT_SHIFT_RIGHT),
-    Token::AssignBitAnd           => NestedHandler(TOP, ASSIGN_BIT_AND),
-    Token::AssignBitXor           => NestedHandler(TOP, ASSIGN_BIT_XOR),
-    Token::AssignBitOr            => NestedHandler(TOP, ASSIGN_BIT_OR),
-    _                             => NestedHandler(INVALID, |_, _| None),
+    Token::Accessor                      => NestedHandler(P2, MEMBER),
+    Token::ParenOpen                     => NestedHandler(P2, CALL),
+    Token::BraceOpen                     => NestedHandler(P2, CALL_OPTIONS),
+    Token::BracketOpen                   => NestedHandler(P2, INDEX),
+    Token::OperatorIncrement             => NestedHandler(P2, INC),
+    Token::OperatorDecrement             => NestedHandler(P2, DEC),
+    Token::OperatorExponent              => NestedHandler(P3, EXPONENT),
+    Token::OperatorMultiplication        => NestedHandler(P4, MUL),
+    Token::OperatorDivision              => NestedHandler(P4, DIV),
+    Token::OperatorRemainder             => NestedHandler(P4, REMAINDER),
+    Token::OperatorAddition              => NestedHandler(P5, ADD),
+    Token::OperatorSubtraction           => NestedHandler(P5, SUB),
+    Token::OperatorBitShiftLeft          => NestedHandler(P6, BIT_SHIFT_LEFT),
+    Token::OperatorBitShiftRight         => NestedHandler(P6, BIT_SHIFT_RIGHT),
+    Token::OperatorBitShiftRightUnsigned => NestedHandler(P6, BIT_SHIFT_RIGHT_UNSIGNED),
+    Token::OperatorBitAnd                => NestedHandler(P7, BIT_AND),
+    Token::OperatorBitXor                => NestedHandler(P8, BIT_XOR),
+    Token::OperatorBitOr                 => NestedHandler(P9, BIT_OR),
+    Token::OperatorLesser                => NestedHandler(P10, LESSER),
+    Token::OperatorLesserEquals          => NestedHandler(P10, LESSER_EQUALITY),
+    Token::OperatorGreater               => NestedHandler(P10, GREATER),
+    Token::OperatorGreaterEquals         => NestedHandler(P10, GREATER_EQUALITY),
+    Token::OperatorEquality              => NestedHandler(P11, EQUALITY),
+    Token::OperatorInequality            => NestedHandler(P11, INEQUALITY),
+    Token::OperatorLogicalAnd            => NestedHandler(P12, LOGICAL_AND),
+    Token::OperatorLogicalOr             => NestedHandler(P13, LOGICAL_OR),
+    Token::OperatorConditional           => NestedHandler(P14, CONDITIONAL),
+    Token::Assign                        => NestedHandler(TOP, ASSIGN),
+    Token::AssignAddition                => NestedHandler(TOP, ASSIGN_ADD),
+    Token::AssignSubtraction             => NestedHandler(TOP, ASSIGN_SUB),
+    Token::AssignMultiplication          => NestedHandler(TOP, ASSIGN_MUL),
+    Token::AssignDivision                => NestedHandler(TOP, ASSIGN_DIV),
+    Token::AssignRemainder               => NestedHandler(TOP, ASSIGN_REM),
+    Token::AssignBitShiftLeft            => NestedHandler(TOP, ASSIGN_BIT_SHIFT_LEFT),
+    Token::AssignBitShiftRight           => NestedHandler(TOP, ASSIGN_BIT_SHIFT_RIGHT),
+    Token::AssignBitShiftRightUnsigned   => NestedHandler(TOP, ASSIGN_BIT_SHIFT_RIGHT_UNSIGNED),
+    Token::AssignBitAnd                  => NestedHandler(TOP, ASSIGN_BIT_AND),
+    Token::AssignBitXor                  => NestedHandler(TOP, ASSIGN_BIT_XOR),
+    Token::AssignBitOr                   => NestedHandler(TOP, ASSIGN_BIT_OR),
+    _                                    => NestedHandler(INVALID, |_, _| None),
 };
 
 impl NestedHandler {
@@ -81,6 +86,10 @@ impl NestedHandler {
 const CALL: HandlerFn = |par, callee| {
     par.lexer.advance();
 
+    if par.lexer.token == Token::BraceOpen {
+        return par.named_call(callee);
+    }
+
     let arguments = par.expression_list();
     let end       = par.expect_end(Token::ParenClose);
 
@@ -90,7 +99,39 @@ const CALL: HandlerFn = |par, callee| {
     })
 };
 
+const CALL_OPTIONS: HandlerFn = |par, callee| {
+    // Blocks can follow expressions, for example in `try` statements.
+    if !par.call_options_ahead() {
+        return None;
+    }
+
+    par.lexer.advance();
+
+    let builder = ListBuilder::new(par.arena, expect!(par, par.named_argument()));
+
+    while par.allow(Token::Comma) {
+        builder.push(par.arena, expect!(par, par.named_argument()));
+    }
+
+    let end = par.expect_end(Token::BraceClose);
+
+    par.node_at(callee.start, end, CallOptionsExpression {
+        callee,
+        options: builder.as_list(),
+    })
+};
+
 const MEMBER: HandlerFn = |par, object| {
+    // Members of elementary types are only accessible via `type(...)`,
+    // for example `type(uint).max` instead of `uint.max`. The exceptions
+    // are `bytes.concat` and `string.concat`.
+    match object.value {
+        Expression::ElementaryTypeExpression(ElementaryTypeName::Bytes)  |
+        Expression::ElementaryTypeExpression(ElementaryTypeName::String) => {},
+        Expression::ElementaryTypeExpression(_)                          => par.error(),
+        _                                                                => {},
+    }
+
     par.lexer.advance();
 
     let member = par.expect_str_node(Token::Identifier);
@@ -179,40 +220,105 @@ macro_rules! binary {
     }
 }
 
-assign!(ASSIGN                 => Plain);
-assign!(ASSIGN_ADD             => Addition);
-assign!(ASSIGN_SUB             => Subtraction);
-assign!(ASSIGN_MUL             => Multiplication);
-assign!(ASSIGN_DIV             => Division);
-assign!(ASSIGN_REM             => Remainder);
-assign!(ASSIGN_BIT_SHIFT_LEFT  => BitShiftLeft);
-assign!(ASSIGN_BIT_SHIFT_RIGHT => BitShiftRight);
-assign!(ASSIGN_BIT_AND         => BitAnd);
-assign!(ASSIGN_BIT_XOR         => BitXor);
-assign!(ASSIGN_BIT_OR          => BitOr);
-
-binary!(LOGICAL_OR       , P13 => LogicalOr);
-binary!(LOGICAL_AND      , P12 => LogicalAnd);
-binary!(EQUALITY         , P11 => Equality);
-binary!(INEQUALITY       , P11 => Inequality);
-binary!(LESSER           , P10 => Lesser);
-binary!(LESSER_EQUALITY  , P10 => LesserEquals);
-binary!(GREATER          , P10 => Greater);
-binary!(GREATER_EQUALITY , P10 => GreaterEquals);
-binary!(BIT_OR           , P9  => BitOr);
-binary!(BIT_XOR          , P8  => BitXor);
-binary!(BIT_AND          , P7  => BitAnd);
-binary!(BIT_SHIFT_LEFT   , P6  => BitShiftLeft);
-binary!(BIT_SHIFT_RIGHT  , P6  => BitShiftRight);
-binary!(ADD              , P5  => Addition);
-binary!(SUB              , P5  => Subtraction);
-binary!(MUL              , P4  => Multiplication);
-binary!(DIV              , P4  => Division);
-binary!(REMAINDER        , P4  => Remainder);
-binary!(EXPONENT         , P3  => Exponent);
+assign!(ASSIGN                          => Plain);
+assign!(ASSIGN_ADD                      => Addition);
+assign!(ASSIGN_SUB                      => Subtraction);
+assign!(ASSIGN_MUL                      => Multiplication);
+assign!(ASSIGN_DIV                      => Division);
+assign!(ASSIGN_REM                      => Remainder);
+assign!(ASSIGN_BIT_SHIFT_LEFT           => BitShiftLeft);
+assign!(ASSIGN_BIT_SHIFT_RIGHT          => BitShiftRight);
+assign!(ASSIGN_BIT_SHIFT_RIGHT_UNSIGNED => BitShiftRightUnsigned);
+assign!(ASSIGN_BIT_AND                  => BitAnd);
+assign!(ASSIGN_BIT_XOR                  => BitXor);
+assign!(ASSIGN_BIT_OR                   => BitOr);
+
+// The precedence is that of the right operand. Using one tighter than the
+// operator itself makes the operator left-associative.
+binary!(LOGICAL_OR               , P12 => LogicalOr);
+binary!(LOGICAL_AND              , P11 => LogicalAnd);
+binary!(EQUALITY                 , P10 => Equality);
+binary!(INEQUALITY               , P10 => Inequality);
+binary!(LESSER                   , P9  => Lesser);
+binary!(LESSER_EQUALITY          , P9  => LesserEquals);
+binary!(GREATER                  , P9  => Greater);
+binary!(GREATER_EQUALITY         , P9  => GreaterEquals);
+binary!(BIT_OR                   , P8  => BitOr);
+binary!(BIT_XOR                  , P7  => BitXor);
+binary!(BIT_AND                  , P6  => BitAnd);
+binary!(BIT_SHIFT_LEFT           , P5  => BitShiftLeft);
+binary!(BIT_SHIFT_RIGHT          , P5  => BitShiftRight);
+binary!(BIT_SHIFT_RIGHT_UNSIGNED , P5  => BitShiftRightUnsigned);
+binary!(ADD                      , P4  => Addition);
+binary!(SUB                      , P4  => Subtraction);
+binary!(MUL                      , P3  => Multiplication);
+binary!(DIV                      , P3  => Division);
+binary!(REMAINDER                , P3  => Remainder);
+
+// Exponentiation is right-associative, `2 ** 3 ** 2` is `2 ** (3 ** 2)`.
+binary!(EXPONENT                 , P3  => Exponent);
 
 
 impl<'ast> Parser<'ast> {
+    /// Check if the current `{` opens a list of call options, that is
+    /// if it's followed by an identifier and a colon.
+    fn call_options_ahead(&self) -> bool {
+        let start = self.lexer.range().end;
+        let rest  = self.lexer.source.slice(start..self.lexer.source.len()).unwrap_or("");
+
+        // Lex ahead on a throwaway lexer, so that comments are skipped
+        let mut ahead = Token::lexer(rest);
+
+        if ahead.token != Token::Identifier {
+            return false;
+        }
+
+        ahead.advance();
+
+        ahead.token == Token::Colon
+    }
+
+    /// Arguments passed by name, `callee({a: 1, b: 2})`, the opening
+    /// parenthesis has already been consumed.
+    fn named_call(&mut self, callee: ExpressionNode<'ast>) -> Option<ExpressionNode<'ast>> {
+        self.lexer.advance();
+
+        let arguments = match self.lexer.token {
+            Token::BraceClose => NodeList::empty(),
+            _                 => {
+                let builder = ListBuilder::new(self.arena, expect!(self, self.named_argument()));
+
+                while self.allow(Token::Comma) {
+                    builder.push(self.arena, expect!(self, self.named_argument()));
+                }
+
+                builder.as_list()
+            },
+        };
+
+        self.expect(Token::BraceClose);
+
+        let end = self.expect_end(Token::ParenClose);
+
+        self.node_at(callee.start, end, NamedCallExpression {
+            callee,
+            arguments,
+        })
+    }
+
+    fn named_argument(&mut self) -> Option<Node<'ast, NamedArgument<'ast>>> {
+        let name = self.expect_str_node(Token::Identifier);
+
+        self.expect(Token::Colon);
+
+        let value = expect!(self, self.expression(TOP));
+
+        self.node_at(name.start, value.end, NamedArgument {
+            name,
+            value,
+        })
+    }
+
     #[inline]
     pub fn nested_expression(&mut self, mut left: ExpressionNode<'ast>, precedence: Precedence) -> ExpressionNode<'ast> {
         while let Some(node) = NESTED_LUT[self.lexer.token as usize].get(precedence).and_then(|handler| handler(self, left)) {
@@ -226,7 +332,7 @@ impl<'ast> Parser<'ast> {
 #[cfg(test)]
 mod test {
     use super::*;
-    use mock::{Mock, assert_units};
+    use mock::{Mock, assert_units, parse_err};
 
     #[test]
     fn nested_expressions() {
@@ -246,6 +352,7 @@ mod test {
 
         "#, [
             m.node(14, 246, ContractDefinition {
+                is_abstract: None,
                 name: m.node(23, 26, "Foo"),
                 inherits: NodeList::empty(),
                 body: m.list([
@@ -254,6 +361,8 @@ mod test {
                         params: NodeList::empty(),
                         visibility: None,
                         mutability: None,
+                        is_virtual: None,
+                        overrides: None,
                         modifiers: NodeList::empty(),
                         returns: NodeList::empty(),
                         block: m.node(56, 232, Block {
@@ -289,6 +398,54 @@ mod test {
         ]);
     }
 
+    #[test]
+    fn postfix_and_prefix_increments() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                function() {
+                    a++ + ++b;
+                }
+            }
+
+        "#, [
+            m.node(14, 120, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 106, FunctionDefinition {
+                        name: None,
+                        params: NodeList::empty(),
+                        visibility: None,
+                        mutability: None,
+                        is_virtual: None,
+                        overrides: None,
+                        modifiers: NodeList::empty(),
+                        returns: NodeList::empty(),
+                        block: m.node(56, 106, Block {
+                            body: m.list([
+                                m.stmt_expr(78, 87, 88, BinaryExpression {
+                                    left: m.node(78, 81, PostfixExpression {
+                                        operand: m.node(78, 79, "a"),
+                                        operator: m.node(79, 81, PostfixOperator::Increment),
+                                    }),
+                                    operator: m.node(82, 83, BinaryOperator::Addition),
+                                    right: m.node(84, 87, PrefixExpression {
+                                        operator: m.node(84, 86, PrefixOperator::Increment),
+                                        operand: m.node(86, 87, "b"),
+                                    }),
+                                }),
+                            ]),
+                        }),
+                    }),
+                ]),
+            }),
+        ]);
+    }
+
     #[test]
     fn binary_expressions() {
         let m = Mock::new();
@@ -321,6 +478,7 @@ mod test {
 
         "#, [
             m.node(14, 611, ContractDefinition {
+                is_abstract: None,
                 name: m.node(23, 26, "Foo"),
                 inherits: NodeList::empty(),
                 body: m.list([
@@ -329,6 +487,8 @@ mod test {
                         params: NodeList::empty(),
                         visibility: None,
                         mutability: None,
+                        is_virtual: None,
+                        overrides: None,
                         modifiers: NodeList::empty(),
                         returns: NodeList::empty(),
                         block: m.node(56, 597, Block {
@@ -461,6 +621,7 @@ mod test {
 
         "#, [
             m.node(14, 398, ContractDefinition {
+                is_abstract: None,
                 name: m.node(23, 26, "Foo"),
                 inherits: NodeList::empty(),
                 body: m.list([
@@ -469,6 +630,8 @@ mod test {
                         params: NodeList::empty(),
                         visibility: None,
                         mutability: None,
+                        is_virtual: None,
+                        overrides: None,
                         modifiers: NodeList::empty(),
                         returns: NodeList::empty(),
                         block: m.node(56, 384, Block {
@@ -551,6 +714,7 @@ mod test {
 
         "#, [
             m.node(14, 169, ContractDefinition {
+                is_abstract: None,
                 name: m.node(23, 26, "Foo"),
                 inherits: NodeList::empty(),
                 body: m.list([
@@ -559,6 +723,8 @@ mod test {
                         params: NodeList::empty(),
                         visibility: None,
                         mutability: None,
+                        is_virtual: None,
+                        overrides: None,
                         modifiers: NodeList::empty(),
                         returns: NodeList::empty(),
                         block: m.node(56, 155, Block {
@@ -602,4 +768,503 @@ mod test {
             }),
         ]);
     }
+
+    #[test]
+    fn comparisons_are_left_associative() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                function() {
+                    a < b < c;
+                    a == b < c;
+                    a < b == c;
+                    a - b - c;
+                }
+            }
+
+        "#, [
+            m.node(14, 215, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 201, FunctionDefinition {
+                        name: None,
+                        params: NodeList::empty(),
+                        visibility: None,
+                        mutability: None,
+                        is_virtual: None,
+                        overrides: None,
+                        modifiers: NodeList::empty(),
+                        returns: NodeList::empty(),
+                        block: m.node(56, 201, Block {
+                            body: m.list([
+                                m.stmt_expr(78, 87, 88, BinaryExpression {
+                                    left: m.node(78, 83, BinaryExpression {
+                                        left: m.node(78, 79, "a"),
+                                        operator: m.node(80, 81, BinaryOperator::Lesser),
+                                        right: m.node(82, 83, "b"),
+                                    }),
+                                    operator: m.node(84, 85, BinaryOperator::Lesser),
+                                    right: m.node(86, 87, "c"),
+                                }),
+                                m.stmt_expr(109, 119, 120, BinaryExpression {
+                                    left: m.node(109, 110, "a"),
+                                    operator: m.node(111, 113, BinaryOperator::Equality),
+                                    right: m.node(114, 119, BinaryExpression {
+                                        left: m.node(114, 115, "b"),
+                                        operator: m.node(116, 117, BinaryOperator::Lesser),
+                                        right: m.node(118, 119, "c"),
+                                    }),
+                                }),
+                                m.stmt_expr(141, 151, 152, BinaryExpression {
+                                    left: m.node(141, 146, BinaryExpression {
+                                        left: m.node(141, 142, "a"),
+                                        operator: m.node(143, 144, BinaryOperator::Lesser),
+                                        right: m.node(145, 146, "b"),
+                                    }),
+                                    operator: m.node(147, 149, BinaryOperator::Equality),
+                                    right: m.node(150, 151, "c"),
+                                }),
+                                m.stmt_expr(173, 182, 183, BinaryExpression {
+                                    left: m.node(173, 178, BinaryExpression {
+                                        left: m.node(173, 174, "a"),
+                                        operator: m.node(175, 176, BinaryOperator::Subtraction),
+                                        right: m.node(177, 178, "b"),
+                                    }),
+                                    operator: m.node(179, 180, BinaryOperator::Subtraction),
+                                    right: m.node(181, 182, "c"),
+                                }),
+                            ]),
+                        }),
+                    }),
+                ]),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn elementary_type_members_require_type_expression() {
+        use parse;
+
+        let errors = parse_err("contract Foo { function() { x = uint.max; } }");
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].token, Token::Accessor);
+        assert_eq!(errors[0].span, 36..37);
+
+        assert!(parse("contract Foo { function() { x = type(uint).max; } }").is_ok());
+        assert!(parse("contract Foo { function() { x = bytes.concat(a, b); } }").is_ok());
+        assert!(parse("contract Foo { function() { x = string.concat(a, b); } }").is_ok());
+    }
+
+    #[test]
+    fn comparison_with_member_access() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                function() {
+                    msg.value >= price;
+                }
+            }
+
+        "#, [
+            m.node(14, 129, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 115, FunctionDefinition {
+                        name: None,
+                        params: NodeList::empty(),
+                        visibility: None,
+                        mutability: None,
+                        is_virtual: None,
+                        overrides: None,
+                        modifiers: NodeList::empty(),
+                        returns: NodeList::empty(),
+                        block: m.node(56, 115, Block {
+                            body: m.list([
+                                m.stmt_expr(78, 96, 97, BinaryExpression {
+                                    left: m.node(78, 87, MemberAccessExpression {
+                                        object: m.node(78, 81, "msg"),
+                                        member: m.node(82, 87, "value"),
+                                    }),
+                                    operator: m.node(88, 90, BinaryOperator::GreaterEquals),
+                                    right: m.node(91, 96, "price"),
+                                }),
+                            ]),
+                        }),
+                    }),
+                ]),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn shift_right_precedence() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                function() {
+                    a >> b + c;
+                    a >>> b < c;
+                }
+            }
+
+        "#, [
+            m.node(14, 154, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 140, FunctionDefinition {
+                        name: None,
+                        params: NodeList::empty(),
+                        visibility: None,
+                        mutability: None,
+                        is_virtual: None,
+                        overrides: None,
+                        modifiers: NodeList::empty(),
+                        returns: NodeList::empty(),
+                        block: m.node(56, 140, Block {
+                            body: m.list([
+                                m.stmt_expr(78, 88, 89, BinaryExpression {
+                                    left: m.node(78, 79, "a"),
+                                    operator: m.node(80, 82, BinaryOperator::BitShiftRight),
+                                    right: m.node(83, 88, BinaryExpression {
+                                        left: m.node(83, 84, "b"),
+                                        operator: m.node(85, 86, BinaryOperator::Addition),
+                                        right: m.node(87, 88, "c"),
+                                    }),
+                                }),
+                                m.stmt_expr(110, 121, 122, BinaryExpression {
+                                    left: m.node(110, 117, BinaryExpression {
+                                        left: m.node(110, 111, "a"),
+                                        operator: m.node(112, 115, BinaryOperator::BitShiftRightUnsigned),
+                                        right: m.node(116, 117, "b"),
+                                    }),
+                                    operator: m.node(118, 119, BinaryOperator::Lesser),
+                                    right: m.node(120, 121, "c"),
+                                }),
+                            ]),
+                        }),
+                    }),
+                ]),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn exponent_is_right_associative() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                function() {
+                    2 ** 3 ** 2;
+                    2 * 3 ** 2;
+                }
+            }
+
+        "#, [
+            m.node(14, 154, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 140, FunctionDefinition {
+                        name: None,
+                        params: NodeList::empty(),
+                        visibility: None,
+                        mutability: None,
+                        is_virtual: None,
+                        overrides: None,
+                        modifiers: NodeList::empty(),
+                        returns: NodeList::empty(),
+                        block: m.node(56, 140, Block {
+                            body: m.list([
+                                m.stmt_expr(78, 89, 90, BinaryExpression {
+                                    left: m.node(78, 79, Primitive::IntegerNumber("2", NumberUnit::None)),
+                                    operator: m.node(80, 82, BinaryOperator::Exponent),
+                                    right: m.node(83, 89, BinaryExpression {
+                                        left: m.node(83, 84, Primitive::IntegerNumber("3", NumberUnit::None)),
+                                        operator: m.node(85, 87, BinaryOperator::Exponent),
+                                        right: m.node(88, 89, Primitive::IntegerNumber("2", NumberUnit::None)),
+                                    }),
+                                }),
+                                m.stmt_expr(111, 121, 122, BinaryExpression {
+                                    left: m.node(111, 112, Primitive::IntegerNumber("2", NumberUnit::None)),
+                                    operator: m.node(113, 114, BinaryOperator::Multiplication),
+                                    right: m.node(115, 121, BinaryExpression {
+                                        left: m.node(115, 116, Primitive::IntegerNumber("3", NumberUnit::None)),
+                                        operator: m.node(117, 119, BinaryOperator::Exponent),
+                                        right: m.node(120, 121, Primitive::IntegerNumber("2", NumberUnit::None)),
+                                    }),
+                                }),
+                            ]),
+                        }),
+                    }),
+                ]),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn address_members_and_call_options() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                function() {
+                    msg.sender.transfer(1 ether);
+                    addr.call{value: 1}("");
+                }
+            }
+
+        "#, [
+            m.node(14, 184, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 170, FunctionDefinition {
+                        name: None,
+                        params: NodeList::empty(),
+                        visibility: None,
+                        mutability: None,
+                        is_virtual: None,
+                        overrides: None,
+                        modifiers: NodeList::empty(),
+                        returns: NodeList::empty(),
+                        block: m.node(56, 170, Block {
+                            body: m.list([
+                                m.stmt_expr(78, 106, 107, CallExpression {
+                                    callee: m.node(78, 97, MemberAccessExpression {
+                                        object: m.node(78, 88, MemberAccessExpression {
+                                            object: m.node(78, 81, "msg"),
+                                            member: m.node(82, 88, "sender"),
+                                        }),
+                                        member: m.node(89, 97, "transfer"),
+                                    }),
+                                    arguments: m.list([
+                                        m.node(98, 105, Primitive::IntegerNumber("1", NumberUnit::Ether(EtherUnit::Ether))),
+                                    ]),
+                                }),
+                                m.stmt_expr(128, 151, 152, CallExpression {
+                                    callee: m.node(128, 147, CallOptionsExpression {
+                                        callee: m.node(128, 137, MemberAccessExpression {
+                                            object: m.node(128, 132, "addr"),
+                                            member: m.node(133, 137, "call"),
+                                        }),
+                                        options: m.list([
+                                            m.node(138, 146, NamedArgument {
+                                                name: m.node(138, 143, "value"),
+                                                value: m.node(145, 146, Primitive::IntegerNumber("1", NumberUnit::None)),
+                                            }),
+                                        ]),
+                                    }),
+                                    arguments: m.list([
+                                        m.node(148, 150, Primitive::String("\"\"")),
+                                    ]),
+                                }),
+                            ]),
+                        }),
+                    }),
+                ]),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn call_options_with_comments() {
+        use parse;
+
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                function() {
+                    addr.call{ /* gas */ value: 1 }("");
+                }
+            }
+
+        "#, [
+            m.node(14, 146, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 132, FunctionDefinition {
+                        name: None,
+                        params: NodeList::empty(),
+                        visibility: None,
+                        mutability: None,
+                        is_virtual: None,
+                        overrides: None,
+                        modifiers: NodeList::empty(),
+                        returns: NodeList::empty(),
+                        block: m.node(56, 132, Block {
+                            body: m.list([
+                                m.stmt_expr(78, 113, 114, CallExpression {
+                                    callee: m.node(78, 109, CallOptionsExpression {
+                                        callee: m.node(78, 87, MemberAccessExpression {
+                                            object: m.node(78, 82, "addr"),
+                                            member: m.node(83, 87, "call"),
+                                        }),
+                                        options: m.list([
+                                            m.node(99, 107, NamedArgument {
+                                                name: m.node(99, 104, "value"),
+                                                value: m.node(106, 107, Primitive::IntegerNumber("1", NumberUnit::None)),
+                                            }),
+                                        ]),
+                                    }),
+                                    arguments: m.list([
+                                        m.node(110, 112, Primitive::String("\"\"")),
+                                    ]),
+                                }),
+                            ]),
+                        }),
+                    }),
+                ]),
+            }),
+        ]);
+
+        assert!(parse("contract Foo { function() { addr.call{\n // note\n value: 1}(\"\"); } }").is_ok());
+    }
+
+    #[test]
+    fn bytes_length_and_index() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                function f() {
+                    bytes memory data = msg.data;
+                    uint len = data.length;
+                    bytes1 first = data[0];
+                }
+            }
+
+        "#, [
+            m.node(14, 229, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 215, FunctionDefinition {
+                        name: m.node(54, 55, "f"),
+                        params: NodeList::empty(),
+                        visibility: None,
+                        mutability: None,
+                        is_virtual: None,
+                        overrides: None,
+                        modifiers: NodeList::empty(),
+                        returns: NodeList::empty(),
+                        block: m.node(58, 215, Block {
+                            body: m.list([
+                                m.node(80, 109, VariableDefinitionStatement {
+                                    declaration: m.node(80, 97, VariableDeclaration {
+                                        type_name: m.node(80, 85, ElementaryTypeName::Bytes),
+                                        location: m.node(86, 92, StorageLocation::Memory),
+                                        id: m.node(93, 97, "data"),
+                                    }),
+                                    init: m.node(100, 108, MemberAccessExpression {
+                                        object: m.node(100, 103, "msg"),
+                                        member: m.node(104, 108, "data"),
+                                    }),
+                                }),
+                                m.node(130, 153, VariableDefinitionStatement {
+                                    declaration: m.node(130, 138, VariableDeclaration {
+                                        type_name: m.node(130, 134, ElementaryTypeName::Uint(32)),
+                                        location: None,
+                                        id: m.node(135, 138, "len"),
+                                    }),
+                                    init: m.node(141, 152, MemberAccessExpression {
+                                        object: m.node(141, 145, "data"),
+                                        member: m.node(146, 152, "length"),
+                                    }),
+                                }),
+                                m.node(174, 197, VariableDefinitionStatement {
+                                    declaration: m.node(174, 186, VariableDeclaration {
+                                        type_name: m.node(174, 180, ElementaryTypeName::Byte(1)),
+                                        location: None,
+                                        id: m.node(181, 186, "first"),
+                                    }),
+                                    init: m.node(189, 196, IndexAccessExpression {
+                                        array: m.node(189, 193, "data"),
+                                        index: m.node(194, 195, Primitive::IntegerNumber("0", NumberUnit::None)),
+                                    }),
+                                }),
+                            ]),
+                        }),
+                    }),
+                ]),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn struct_construction_with_named_arguments() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                function f() {
+                    s = S({a: 1, b: 2});
+                }
+            }
+
+        "#, [
+            m.node(14, 132, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 118, FunctionDefinition {
+                        name: m.node(54, 55, "f"),
+                        params: NodeList::empty(),
+                        visibility: None,
+                        mutability: None,
+                        is_virtual: None,
+                        overrides: None,
+                        modifiers: NodeList::empty(),
+                        returns: NodeList::empty(),
+                        block: m.node(58, 118, Block {
+                            body: m.list([
+                                m.stmt_expr(80, 99, 100, AssignmentExpression {
+                                    left: m.node(80, 81, "s"),
+                                    operator: m.node(82, 83, AssignmentOperator::Plain),
+                                    right: m.node(84, 99, NamedCallExpression {
+                                        callee: m.node(84, 85, "S"),
+                                        arguments: m.list([
+                                            m.node(87, 91, NamedArgument {
+                                                name: m.node(87, 88, "a"),
+                                                value: m.node(90, 91, Primitive::IntegerNumber("1", NumberUnit::None)),
+                                            }),
+                                            m.node(93, 97, NamedArgument {
+                                                name: m.node(93, 94, "b"),
+                                                value: m.node(96, 97, Primitive::IntegerNumber("2", NumberUnit::None)),
+                                            }),
+                                        ]),
+                                    }),
+                                }),
+                            ]),
+                        }),
+                    }),
+                ]),
+            }),
+        ]);
+    }
 }
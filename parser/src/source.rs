@@ -10,6 +10,13 @@ impl<'ast> Parser<'ast> {
             Token::KeywordPragma => self.pragma_directive(),
             Token::KeywordImport => self.import_directive(),
             Token::DeclarationContract => self.contract_definition(),
+            Token::ReservedWord if self.lexer.slice() == "abstract" => self.contract_definition(),
+            Token::DeclarationEvent => self.event_definition(),
+            Token::KeywordUsing => self.using_for_declaration(),
+            Token::DeclarationFunction => self.free_function_definition(),
+            Token::Identifier if self.lexer.slice() == "error" => self.error_definition(),
+            Token::Identifier => self.file_level_constant(),
+            token if token.is_elementary_type() => self.file_level_constant(),
             _ => None,
         }
     }
@@ -17,16 +24,43 @@ impl<'ast> Parser<'ast> {
     fn pragma_directive(&mut self) -> Option<SourceUnitNode<'ast>> {
         let start = self.start_then_advance();
 
-        if self.lexer.token != Token::Identifier || self.lexer.slice() != "solidity" {
-            self.error();
-        }
+        match (self.lexer.token, self.lexer.slice()) {
+            (Token::Identifier, "experimental") => {
+                self.lexer.advance();
 
-        let version = ::lexer::read_pragma(&mut self.lexer);
-        let end     = self.expect_end(Token::Semicolon);
+                let feature = match self.lexer.token {
+                    Token::LiteralString => self.str_node(),
+                    _                    => self.expect_str_node(Token::Identifier),
+                };
+                let end = self.expect_end(Token::Semicolon);
 
-        self.node_at(start, end, PragmaDirective {
-            version
-        })
+                self.node_at(start, end, ExperimentalPragma {
+                    feature
+                })
+            },
+            (Token::Identifier, "abicoder") => {
+                self.lexer.advance();
+
+                let version = self.expect_str_node(Token::Identifier);
+                let end     = self.expect_end(Token::Semicolon);
+
+                self.node_at(start, end, AbicoderPragma {
+                    version
+                })
+            },
+            (token, name) => {
+                if token != Token::Identifier || name != "solidity" {
+                    self.error();
+                }
+
+                let version = self.lexer.read_pragma();
+                let end     = self.expect_end(Token::Semicolon);
+
+                self.node_at(start, end, PragmaDirective {
+                    version
+                })
+            },
+        }
     }
 
     fn import_directive(&mut self) -> Option<SourceUnitNode<'ast>> {
@@ -36,6 +70,10 @@ impl<'ast> Parser<'ast> {
             Token::OperatorMultiplication => {
                 self.lexer.advance();
 
+                if self.lexer.token == Token::KeywordAs {
+                    return self.import_directive_namespace(start);
+                }
+
                 None
             },
             Token::Identifier    => self.str_node(),
@@ -69,6 +107,22 @@ impl<'ast> Parser<'ast> {
         })
     }
 
+    fn import_directive_namespace(&mut self, start: u32) -> Option<SourceUnitNode<'ast>> {
+        self.lexer.advance();
+
+        let alias = self.expect_str_node(Token::Identifier);
+
+        self.expect_exact(Token::Identifier, "from");
+
+        let source = self.expect_str_node(Token::LiteralString);
+        let end    = self.expect_end(Token::Semicolon);
+
+        self.node_at(start, end, ImportDirective::Namespace {
+            alias,
+            source,
+        })
+    }
+
     fn import_directive_from_many(&mut self, start: u32) -> Option<SourceUnitNode<'ast>> {
         self.lexer.advance();
 
@@ -117,7 +171,7 @@ impl<'ast> Parser<'ast> {
 #[cfg(test)]
 mod test {
     use super::*;
-    use mock::{Mock, assert_units};
+    use mock::{Mock, assert_units, parse_err};
 
     #[test]
     fn pragma() {
@@ -130,6 +184,33 @@ mod test {
         ]);
     }
 
+    #[test]
+    fn pragma_experimental_and_abicoder() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            pragma experimental ABIEncoderV2;
+            pragma experimental "v0.5.0";
+            pragma abicoder v2;
+            pragma solidity >=0.4.22 <0.6.0;
+
+            "#, [
+            m.node(14, 47, ExperimentalPragma {
+                feature: m.node(34, 46, "ABIEncoderV2"),
+            }),
+            m.node(60, 89, ExperimentalPragma {
+                feature: m.node(80, 88, "\"v0.5.0\""),
+            }),
+            m.node(102, 121, AbicoderPragma {
+                version: m.node(118, 120, "v2"),
+            }),
+            m.node(134, 166, PragmaDirective {
+                version: "solidity >=0.4.22 <0.6.0",
+            }),
+        ]);
+    }
+
     #[test]
     fn import() {
         let m = Mock::new();
@@ -176,6 +257,18 @@ mod test {
         ]);
     }
 
+    #[test]
+    fn import_path_verbatim() {
+        let m = Mock::new();
+
+        assert_units(r#"import "../node_modules/@openzeppelin/contracts/token/ERC20/ERC20.sol";"#, [
+            m.node(0, 71, ImportDirective::Global {
+                source: m.node(7, 70, "\"../node_modules/@openzeppelin/contracts/token/ERC20/ERC20.sol\""),
+                alias: None,
+            }),
+        ]);
+    }
+
     #[test]
     fn import_aliases() {
         let m = Mock::new();
@@ -192,8 +285,7 @@ mod test {
                 source: m.node(21, 26, "\"foo\""),
                 alias: m.node(30, 39, "globalFoo"),
             }),
-            m.node(53, 86, ImportDirective::From {
-                symbol: None,
+            m.node(53, 86, ImportDirective::Namespace {
                 alias: m.node(65, 74, "globalBar"),
                 source: m.node(80, 85, "\"bar\""),
             }),
@@ -221,4 +313,311 @@ mod test {
             })
         ]);
     }
+
+    #[test]
+    fn import_mixed_aliases() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            import { Foo as Bar, Baz, Qux as Quux } from "./x.sol";
+
+            "#, [
+            m.node(14, 69, ImportDirective::ManyFrom {
+                imports: m.list([
+                    m.node(23, 33, Import {
+                        symbol: m.node(23, 26, "Foo"),
+                        alias: m.node(30, 33, "Bar"),
+                    }),
+                    m.node(35, 38, Import {
+                        symbol: m.node(35, 38, "Baz"),
+                        alias: None,
+                    }),
+                    m.node(40, 51, Import {
+                        symbol: m.node(40, 43, "Qux"),
+                        alias: m.node(47, 51, "Quux"),
+                    }),
+                ]),
+                source: m.node(59, 68, "\"./x.sol\""),
+            })
+        ]);
+    }
+
+    #[test]
+    fn import_namespace() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            import * as Utils from "./utils.sol";
+            import Utils from "./utils.sol";
+            import { Utils } from "./utils.sol";
+            import * from "./utils.sol";
+
+            "#, [
+            m.node(14, 51, ImportDirective::Namespace {
+                alias: m.node(26, 31, "Utils"),
+                source: m.node(37, 50, "\"./utils.sol\""),
+            }),
+            m.node(64, 96, ImportDirective::From {
+                symbol: m.node(71, 76, "Utils"),
+                alias: None,
+                source: m.node(82, 95, "\"./utils.sol\""),
+            }),
+            m.node(109, 145, ImportDirective::ManyFrom {
+                imports: m.list([
+                    m.node(118, 123, Import {
+                        symbol: m.node(118, 123, "Utils"),
+                        alias: None,
+                    }),
+                ]),
+                source: m.node(131, 144, "\"./utils.sol\""),
+            }),
+            m.node(158, 186, ImportDirective::From {
+                symbol: None,
+                alias: None,
+                source: m.node(172, 185, "\"./utils.sol\""),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn file_level_event() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            event Transfer(address indexed from, address indexed to, uint256 value);
+
+            contract Foo {}
+
+        "#, [
+            m.node(14, 86, EventDefinition {
+                anonymous: None,
+                name: m.node(20, 28, "Transfer"),
+                params: m.list([
+                    m.node(29, 49, IndexedParameter {
+                        type_name: m.node(29, 36, ElementaryTypeName::Address),
+                        indexed: m.node(37, 44, Flag),
+                        name: m.node(45, 49, "from"),
+                    }),
+                    m.node(51, 69, IndexedParameter {
+                        type_name: m.node(51, 58, ElementaryTypeName::Address),
+                        indexed: m.node(59, 66, Flag),
+                        name: m.node(67, 69, "to"),
+                    }),
+                    m.node(71, 84, IndexedParameter {
+                        type_name: m.node(71, 78, ElementaryTypeName::Uint(32)),
+                        indexed: None,
+                        name: m.node(79, 84, "value"),
+                    }),
+                ]),
+            }),
+            m.node(100, 115, ContractDefinition {
+                is_abstract: None,
+                name: m.node(109, 112, "Foo"),
+                inherits: NodeList::empty(),
+                body: NodeList::empty(),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn file_and_contract_level_errors() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            error Unauthorized(address caller);
+
+            contract Foo {
+                error Empty();
+            }
+
+        "#, [
+            m.node(14, 49, ErrorDefinition {
+                name: m.node(20, 32, "Unauthorized"),
+                params: m.list([
+                    m.node(33, 47, Parameter {
+                        type_name: m.node(33, 40, ElementaryTypeName::Address),
+                        location: None,
+                        name: m.node(41, 47, "caller"),
+                    }),
+                ]),
+            }),
+            m.node(63, 122, ContractDefinition {
+                is_abstract: None,
+                name: m.node(72, 75, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(94, 108, ErrorDefinition {
+                        name: m.node(100, 105, "Empty"),
+                        params: NodeList::empty(),
+                    }),
+                ]),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn file_level_using_for() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            using Lib for uint global;
+            using Lib for uint;
+
+        "#, [
+            m.node(14, 40, UsingForDeclaration {
+                id: m.node(20, 23, "Lib"),
+                functions: NodeList::empty(),
+                type_name: m.node(28, 32, ElementaryTypeName::Uint(32)),
+                global: m.node(33, 39, Flag),
+            }),
+            m.node(53, 72, UsingForDeclaration {
+                id: m.node(59, 62, "Lib"),
+                functions: NodeList::empty(),
+                type_name: m.node(67, 71, ElementaryTypeName::Uint(32)),
+                global: None,
+            }),
+        ]);
+    }
+
+    #[test]
+    fn using_for_with_operators() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            using {add as +, sub as -, neg} for UD global;
+
+        "#, [
+            m.node(14, 60, UsingForDeclaration {
+                id: None,
+                functions: m.list([
+                    m.node(21, 29, UsingFunction {
+                        function: m.node(21, 24, "add"),
+                        operator: m.node(28, 29, UserOperator::Addition),
+                    }),
+                    m.node(31, 39, UsingFunction {
+                        function: m.node(31, 34, "sub"),
+                        operator: m.node(38, 39, UserOperator::Subtraction),
+                    }),
+                    m.node(41, 44, UsingFunction {
+                        function: m.node(41, 44, "neg"),
+                        operator: None,
+                    }),
+                ]),
+                type_name: m.node(50, 52, "UD"),
+                global: m.node(53, 59, Flag),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn free_function() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            function double(uint x) pure returns (uint) {
+                return x * 2;
+            }
+
+        "#, [
+            m.node(14, 103, FunctionDefinition {
+                name: m.node(23, 29, "double"),
+                params: m.list([
+                    m.node(30, 36, Parameter {
+                        type_name: m.node(30, 34, ElementaryTypeName::Uint(32)),
+                        location: None,
+                        name: m.node(35, 36, "x"),
+                    }),
+                ]),
+                visibility: None,
+                mutability: m.node(38, 42, StateMutability::Pure),
+                is_virtual: None,
+                overrides: None,
+                modifiers: NodeList::empty(),
+                returns: m.list([
+                    m.node(52, 56, Parameter {
+                        type_name: m.node(52, 56, ElementaryTypeName::Uint(32)),
+                        location: None,
+                        name: None,
+                    }),
+                ]),
+                block: m.node(58, 103, Block {
+                    body: m.list([
+                        m.node(76, 89, ReturnStatement {
+                            value: m.node(83, 88, BinaryExpression {
+                                left: m.node(83, 84, "x"),
+                                operator: m.node(85, 86, BinaryOperator::Multiplication),
+                                right: m.node(87, 88, Primitive::IntegerNumber("2", NumberUnit::None)),
+                            }),
+                        }),
+                    ]),
+                }),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn free_function_cannot_have_visibility() {
+        use error::Error;
+
+        let errors = parse_err("function foo() public {}");
+
+        assert_eq!(errors, vec![Error {
+            token: Token::KeywordPublic,
+            raw: "public".into(),
+            span: 15..21,
+            since: None,
+        }]);
+    }
+
+    #[test]
+    fn file_level_constant() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            uint256 constant MAX = 2 ** 200;
+
+        "#, [
+            m.node(14, 46, StateVariableDeclaration {
+                type_name: m.node(14, 21, ElementaryTypeName::Uint(32)),
+                visibility: None,
+                constant: m.node(22, 30, Flag),
+                name: m.node(31, 34, "MAX"),
+                init: m.node(37, 45, BinaryExpression {
+                    left: m.node(37, 38, Primitive::IntegerNumber("2", NumberUnit::None)),
+                    operator: m.node(39, 41, BinaryOperator::Exponent),
+                    right: m.node(42, 45, Primitive::IntegerNumber("200", NumberUnit::None)),
+                }),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn file_level_variables_must_be_constant() {
+        use error::Error;
+
+        let errors = parse_err("uint public foo = 1;");
+
+        assert_eq!(errors, vec![
+            Error {
+                token: Token::KeywordPublic,
+                raw: "public".into(),
+                span: 5..11,
+                since: None,
+            },
+            Error {
+                token: Token::Identifier,
+                raw: "foo".into(),
+                span: 12..15,
+                since: None,
+            },
+        ]);
+    }
 }
@@ -3,6 +3,7 @@ use toolshed::list::{List, GrowableList, ListBuilder};
 use ast::*;
 use {Parser, TOP, StatementTypeNameContext};
 use lexer::Token;
+use version::UNCHECKED_BLOCKS;
 
 /// A trait that allows for extra statements to be parsed in a specific context.
 /// In particular, it's used to differentiate between function and modifier
@@ -11,7 +12,7 @@ pub trait StatementContext<'ast> {
     type LoopContext: StatementContext<'ast>;
 
     #[inline]
-    fn pre_parse(&mut Parser<'ast>) -> Option<StatementNode<'ast>> {
+    fn pre_parse(_: &mut Parser<'ast>) -> Option<StatementNode<'ast>> {
         None
     }
 }
@@ -83,7 +84,11 @@ impl<'ast> Parser<'ast> {
             Token::KeywordReturn   => self.return_statement(),
             Token::KeywordThrow    => self.token_statement(ThrowStatement),
             Token::KeywordAssembly => self.inline_assembly_statement(),
+            Token::KeywordTry      => self.try_statement::<Context>(),
             Token::DeclarationVar  => self.inferred_definition_statement(),
+            Token::Semicolon       => self.node_at_token(EmptyStatement),
+            Token::Identifier if self.lexer.slice() == "unchecked" => self.unchecked_statement::<Context>(),
+            Token::Identifier if self.lexer.slice() == "emit" => self.emit_statement(),
 
             _ => match self.variable_definition_statement() {
                 None => self.expression_statement(),
@@ -256,6 +261,20 @@ impl<'ast> Parser<'ast> {
         let start  = self.start_then_advance();
         let string = self.allow_str_node(Token::LiteralString);
 
+        let flags = if self.allow(Token::ParenOpen) {
+            let builder = ListBuilder::new(self.arena, self.expect_str_node(Token::LiteralString));
+
+            while self.allow(Token::Comma) {
+                builder.push(self.arena, self.expect_str_node(Token::LiteralString));
+            }
+
+            self.expect(Token::ParenClose);
+
+            builder.as_list()
+        } else {
+            NodeList::empty()
+        };
+
         if self.lexer.token != Token::BraceOpen {
             self.error();
         }
@@ -264,10 +283,121 @@ impl<'ast> Parser<'ast> {
 
         self.node_at(start, block.end, InlineAssemblyStatement {
             string,
+            flags,
             block,
         })
     }
 
+    fn try_statement<Context>(&mut self) -> Option<StatementNode<'ast>>
+    where
+        Context: StatementContext<'ast>,
+    {
+        let start      = self.start_then_advance();
+        let expression = expect!(self, self.expression(TOP));
+        let returns    = self.function_returns();
+        let body       = self.block::<Context, _>();
+        let catches    = GrowableList::new();
+
+        let mut end = body.end;
+
+        while self.lexer.token == Token::KeywordCatch {
+            let start = self.start_then_advance();
+            let name  = self.allow_str_node(Token::Identifier);
+
+            let params = if self.allow(Token::ParenOpen) {
+                let params = self.parameter_list();
+
+                self.expect(Token::ParenClose);
+
+                params
+            } else {
+                NodeList::empty()
+            };
+
+            let body = self.block::<Context, _>();
+
+            end = body.end;
+
+            let clause = self.node_at(start, end, CatchClause {
+                name,
+                params,
+                body,
+            });
+
+            catches.push(self.arena, clause);
+        }
+
+        let catches = catches.as_list();
+
+        if catches.is_empty() {
+            self.error();
+        }
+
+        self.node_at(start, end, TryStatement {
+            expression,
+            returns,
+            body,
+            catches,
+        })
+    }
+
+    fn unchecked_statement<Context>(&mut self) -> Option<StatementNode<'ast>>
+    where
+        Context: StatementContext<'ast>,
+    {
+        let ident        = self.lexer.slice();
+        let (start, end) = self.loc();
+
+        self.lexer.advance();
+
+        if self.lexer.token == Token::BraceOpen {
+            self.require_version(UNCHECKED_BLOCKS, Token::Identifier, start as usize..end as usize);
+
+            let block = self.block::<Context, _>();
+
+            return self.node_at(start, block.end, UncheckedBlockStatement {
+                block,
+            });
+        }
+
+        // Not followed by a block, `unchecked` is just an identifier
+        let expression = self.node_at(start, end, ident);
+        let expression = self.nested_expression(expression, TOP);
+        let end        = self.expect_end(Token::Semicolon);
+
+        self.node_at(start, end, expression)
+    }
+
+    fn emit_statement(&mut self) -> Option<StatementNode<'ast>> {
+        let ident        = self.lexer.slice();
+        let (start, end) = self.loc();
+
+        self.lexer.advance();
+
+        if self.lexer.token == Token::Identifier {
+            let call = expect!(self, self.expression(TOP));
+
+            // The event has to be invoked, report anything else at its end
+            match call.value {
+                Expression::CallExpression(_) => {},
+                _                             => self.error(),
+            }
+
+            let end = self.expect_end(Token::Semicolon);
+
+            return self.node_at(start, end, EmitStatement {
+                call,
+            });
+        }
+
+        // Not followed by an event name, `emit` is just an identifier
+        let expression = self.node_at(start, end, ident);
+        let expression = self.nested_expression(expression, TOP);
+        let end        = self.expect_end(Token::Semicolon);
+
+        self.node_at(start, end, expression)
+    }
+
     fn expression_statement<S>(&mut self) -> Option<Node<'ast, S>>
     where
         S: From<ExpressionNode<'ast>> + Copy,
@@ -350,7 +480,7 @@ impl<'ast> Parser<'ast> {
 #[cfg(test)]
 mod test {
     use super::*;
-    use mock::{Mock, assert_units};
+    use mock::{Mock, assert_units, parse_err_with};
 
     #[test]
     fn empty_block() {
@@ -364,6 +494,7 @@ mod test {
 
         "#, [
             m.node(14, 76, ContractDefinition {
+                is_abstract: None,
                 name: m.node(23, 26, "Foo"),
                 inherits: NodeList::empty(),
                 body: m.list([
@@ -372,6 +503,8 @@ mod test {
                         params: NodeList::empty(),
                         visibility: None,
                         mutability: None,
+                        is_virtual: None,
+                        overrides: None,
                         modifiers: NodeList::empty(),
                         returns: NodeList::empty(),
                         block: m.node(60, 62, Block {
@@ -397,6 +530,7 @@ mod test {
 
         "#, [
             m.node(14, 116, ContractDefinition {
+                is_abstract: None,
                 name: m.node(23, 26, "Foo"),
                 inherits: NodeList::empty(),
                 body: m.list([
@@ -405,6 +539,8 @@ mod test {
                         params: NodeList::empty(),
                         visibility: None,
                         mutability: None,
+                        is_virtual: None,
+                        overrides: None,
                         modifiers: NodeList::empty(),
                         returns: NodeList::empty(),
                         block: m.node(60, 102, Block {
@@ -418,6 +554,250 @@ mod test {
         ]);
     }
 
+    #[test]
+    fn block_statement() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                function bar() {
+                    {
+                        uint8 a = 1;
+                        {
+                            a;
+                        }
+                    }
+                }
+            }
+
+        "#, [
+            m.node(14, 257, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 243, FunctionDefinition {
+                        name: m.node(54, 57, "bar"),
+                        params: NodeList::empty(),
+                        visibility: None,
+                        mutability: None,
+                        is_virtual: None,
+                        overrides: None,
+                        modifiers: NodeList::empty(),
+                        returns: NodeList::empty(),
+                        block: m.node(60, 243, Block {
+                            body: m.list([
+                                m.node(82, 225, Block {
+                                    body: m.list([
+                                        m.node(108, 120, VariableDefinitionStatement {
+                                            declaration: m.node(108, 115, VariableDeclaration {
+                                                type_name: m.node(108, 113, ElementaryTypeName::Uint(1)),
+                                                location: None,
+                                                id: m.node(114, 115, "a"),
+                                            }),
+                                            init: m.node(118, 119, Primitive::IntegerNumber("1", NumberUnit::None)),
+                                        }),
+                                        m.node(145, 203, Block {
+                                            body: m.list([
+                                                m.stmt_expr(175, 176, 177, "a"),
+                                            ]),
+                                        }),
+                                    ]),
+                                }),
+                            ]),
+                        }),
+                    }),
+                ]),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn call_expression_statements() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                function bar() {
+                    foo();
+                    bar(1, 2);
+                }
+            }
+
+        "#, [
+            m.node(14, 151, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 137, FunctionDefinition {
+                        name: m.node(54, 57, "bar"),
+                        params: NodeList::empty(),
+                        visibility: None,
+                        mutability: None,
+                        is_virtual: None,
+                        overrides: None,
+                        modifiers: NodeList::empty(),
+                        returns: NodeList::empty(),
+                        block: m.node(60, 137, Block {
+                            body: m.list([
+                                m.stmt_expr(82, 87, 88, CallExpression {
+                                    callee: m.node(82, 85, "foo"),
+                                    arguments: NodeList::empty(),
+                                }),
+                                m.stmt_expr(109, 118, 119, CallExpression {
+                                    callee: m.node(109, 112, "bar"),
+                                    arguments: m.list([
+                                        m.node(113, 114, Primitive::IntegerNumber("1", NumberUnit::None)),
+                                        m.node(116, 117, Primitive::IntegerNumber("2", NumberUnit::None)),
+                                    ]),
+                                }),
+                            ]),
+                        }),
+                    }),
+                ]),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn unchecked_block_statement() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                function bar() {
+                    unchecked { x; }
+                    uint unchecked = 1;
+                    unchecked += 1;
+                }
+            }
+
+        "#, [
+            m.node(14, 206, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 192, FunctionDefinition {
+                        name: m.node(54, 57, "bar"),
+                        params: NodeList::empty(),
+                        visibility: None,
+                        mutability: None,
+                        is_virtual: None,
+                        overrides: None,
+                        modifiers: NodeList::empty(),
+                        returns: NodeList::empty(),
+                        block: m.node(60, 192, Block {
+                            body: m.list([
+                                m.node(82, 98, UncheckedBlockStatement {
+                                    block: m.node(92, 98, Block {
+                                        body: m.list([
+                                            m.stmt_expr(94, 95, 96, "x"),
+                                        ]),
+                                    }),
+                                }),
+                                m.node(119, 138, VariableDefinitionStatement {
+                                    declaration: m.node(119, 133, VariableDeclaration {
+                                        type_name: m.node(119, 123, ElementaryTypeName::Uint(32)),
+                                        location: None,
+                                        id: m.node(124, 133, "unchecked"),
+                                    }),
+                                    init: m.node(136, 137, Primitive::IntegerNumber("1", NumberUnit::None)),
+                                }),
+                                m.stmt_expr(159, 173, 174, AssignmentExpression {
+                                    left: m.node(159, 168, "unchecked"),
+                                    operator: m.node(169, 171, AssignmentOperator::Addition),
+                                    right: m.node(172, 173, Primitive::IntegerNumber("1", NumberUnit::None)),
+                                }),
+                            ]),
+                        }),
+                    }),
+                ]),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn unchecked_block_inside_loop() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                function bar() {
+                    for (;;) {
+                        unchecked { ++i; }
+                    }
+                }
+            }
+
+        "#, [
+            m.node(14, 189, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 175, FunctionDefinition {
+                        name: m.node(54, 57, "bar"),
+                        params: NodeList::empty(),
+                        visibility: None,
+                        mutability: None,
+                        is_virtual: None,
+                        overrides: None,
+                        modifiers: NodeList::empty(),
+                        returns: NodeList::empty(),
+                        block: m.node(60, 175, Block {
+                            body: m.list([
+                                m.node(82, 157, ForStatement {
+                                    init: None,
+                                    test: None,
+                                    update: None,
+                                    body: m.node(91, 157, Block {
+                                        body: m.list([
+                                            m.node(117, 135, UncheckedBlockStatement {
+                                                block: m.node(127, 135, Block {
+                                                    body: m.list([
+                                                        m.stmt_expr(129, 132, 133, PrefixExpression {
+                                                            operator: m.node(129, 131, PrefixOperator::Increment),
+                                                            operand: m.node(131, 132, "i"),
+                                                        }),
+                                                    ]),
+                                                }),
+                                            }),
+                                        ]),
+                                    }),
+                                }),
+                            ]),
+                        }),
+                    }),
+                ]),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn unchecked_block_requires_solidity_0_8() {
+        use {parse_with, Version};
+        use error::Error;
+
+        let source = "contract Foo { function() { unchecked {} } }";
+
+        let errors = parse_err_with(source, |parser| parser.target(Version::new(0, 7, 6)));
+
+        assert_eq!(errors, vec![Error {
+            token: Token::Identifier,
+            raw: "unchecked".into(),
+            span: 28..37,
+            since: Some(Version::new(0, 8, 0)),
+        }]);
+
+        assert!(parse_with(source, |parser| parser.target(Version::new(0, 8, 0))).is_ok());
+    }
+
     #[test]
     fn if_statement() {
         let m = Mock::new();
@@ -448,6 +828,7 @@ mod test {
 
         "#, [
             m.node(14, 533, ContractDefinition {
+                is_abstract: None,
                 name: m.node(23, 26, "Foo"),
                 inherits: NodeList::empty(),
                 body: m.list([
@@ -456,6 +837,8 @@ mod test {
                         params: NodeList::empty(),
                         visibility: None,
                         mutability: None,
+                        is_virtual: None,
+                        overrides: None,
                         modifiers: NodeList::empty(),
                         returns: NodeList::empty(),
                         block: m.node(60, 519, Block {
@@ -511,6 +894,52 @@ mod test {
         ]);
     }
 
+    #[test]
+    fn dangling_else_binds_to_nearest_if() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                function bar() {
+                    if (a) if (b) x; else y;
+                }
+            }
+
+        "#, [
+            m.node(14, 138, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 124, FunctionDefinition {
+                        name: m.node(54, 57, "bar"),
+                        params: NodeList::empty(),
+                        visibility: None,
+                        mutability: None,
+                        is_virtual: None,
+                        overrides: None,
+                        modifiers: NodeList::empty(),
+                        returns: NodeList::empty(),
+                        block: m.node(60, 124, Block {
+                            body: m.list([
+                                m.node(82, 106, IfStatement {
+                                    test: m.node(86, 87, "a"),
+                                    consequent: m.node(89, 106, IfStatement {
+                                        test: m.node(93, 94, "b"),
+                                        consequent: m.stmt_expr(96, 97, 98, "x"),
+                                        alternate: m.stmt_expr(104, 105, 106, "y"),
+                                    }),
+                                    alternate: None,
+                                }),
+                            ]),
+                        }),
+                    }),
+                ]),
+            }),
+        ]);
+    }
+
     #[test]
     fn while_statement() {
         let m = Mock::new();
@@ -527,6 +956,7 @@ mod test {
 
         "#, [
             m.node(14, 193, ContractDefinition {
+                is_abstract: None,
                 name: m.node(23, 26, "Foo"),
                 inherits: NodeList::empty(),
                 body: m.list([
@@ -535,6 +965,8 @@ mod test {
                         params: NodeList::empty(),
                         visibility: None,
                         mutability: None,
+                        is_virtual: None,
+                        overrides: None,
                         modifiers: NodeList::empty(),
                         returns: NodeList::empty(),
                         block: m.node(60, 179, Block {
@@ -571,6 +1003,7 @@ mod test {
 
         "#, [
             m.node(14, 216, ContractDefinition {
+                is_abstract: None,
                 name: m.node(23, 26, "Foo"),
                 inherits: NodeList::empty(),
                 body: m.list([
@@ -579,6 +1012,8 @@ mod test {
                         params: NodeList::empty(),
                         visibility: None,
                         mutability: None,
+                        is_virtual: None,
+                        overrides: None,
                         modifiers: NodeList::empty(),
                         returns: NodeList::empty(),
                         block: m.node(60, 202, Block {
@@ -629,6 +1064,7 @@ mod test {
 
         "#, [
             m.node(14, 125, ContractDefinition {
+                is_abstract: None,
                 name: m.node(23, 26, "Foo"),
                 inherits: NodeList::empty(),
                 body: m.list([
@@ -637,6 +1073,8 @@ mod test {
                         params: NodeList::empty(),
                         visibility: None,
                         mutability: None,
+                        is_virtual: None,
+                        overrides: None,
                         modifiers: NodeList::empty(),
                         returns: NodeList::empty(),
                         block: m.node(60, 111, Block {
@@ -673,6 +1111,7 @@ mod test {
 
         "#, [
             m.node(14, 197, ContractDefinition {
+                is_abstract: None,
                 name: m.node(23, 26, "Foo"),
                 inherits: NodeList::empty(),
                 body: m.list([
@@ -681,6 +1120,8 @@ mod test {
                         params: NodeList::empty(),
                         visibility: None,
                         mutability: None,
+                        is_virtual: None,
+                        overrides: None,
                         modifiers: NodeList::empty(),
                         returns: NodeList::empty(),
                         block: m.node(60, 183, Block {
@@ -721,6 +1162,7 @@ mod test {
 
         "#, [
             m.node(14, 268, ContractDefinition {
+                is_abstract: None,
                 name: m.node(23, 26, "Foo"),
                 inherits: NodeList::empty(),
                 body: m.list([
@@ -729,6 +1171,8 @@ mod test {
                         params: NodeList::empty(),
                         visibility: None,
                         mutability: None,
+                        is_virtual: None,
+                        overrides: None,
                         modifiers: NodeList::empty(),
                         returns: NodeList::empty(),
                         block: m.node(60, 254, Block {
@@ -800,6 +1244,7 @@ mod test {
 
         "#, [
             m.node(14, 180, ContractDefinition {
+                is_abstract: None,
                 name: m.node(23, 26, "Foo"),
                 inherits: NodeList::empty(),
                 body: m.list([
@@ -808,6 +1253,8 @@ mod test {
                         params: NodeList::empty(),
                         visibility: None,
                         mutability: None,
+                        is_virtual: None,
+                        overrides: None,
                         modifiers: NodeList::empty(),
                         returns: NodeList::empty(),
                         block: m.node(60, 166, Block {
@@ -828,43 +1275,95 @@ mod test {
     }
 
     #[test]
-    fn variable_definition_statement() {
+    fn return_tuple_statement() {
         let m = Mock::new();
 
         assert_units(r#"
 
             contract Foo {
-                function wow() {
-                    uint foo = 10;
-                    bool memory bar = true;
-                    string storage baz;
+                function bar() {
+                    return (x, y);
+                    return x;
                 }
             }
 
         "#, [
-            m.node(14, 212, ContractDefinition {
+            m.node(14, 158, ContractDefinition {
+                is_abstract: None,
                 name: m.node(23, 26, "Foo"),
                 inherits: NodeList::empty(),
                 body: m.list([
-                    m.node(45, 198, FunctionDefinition {
-                        name: m.node(54, 57, "wow"),
+                    m.node(45, 144, FunctionDefinition {
+                        name: m.node(54, 57, "bar"),
                         params: NodeList::empty(),
                         visibility: None,
                         mutability: None,
+                        is_virtual: None,
+                        overrides: None,
                         modifiers: NodeList::empty(),
                         returns: NodeList::empty(),
-                        block: m.node(60, 198, Block {
+                        block: m.node(60, 144, Block {
                             body: m.list([
-                                m.node(82, 96, VariableDefinitionStatement {
-                                    declaration: m.node(82, 90, VariableDeclaration {
-                                        type_name: m.node(82, 86, ElementaryTypeName::Uint(32)),
-                                        location: None,
-                                        id: m.node(87, 90, "foo"),
+                                m.node(82, 96, ReturnStatement {
+                                    value: m.node(89, 95, TupleExpression {
+                                        expressions: m.list([
+                                            m.node(90, 91, "x"),
+                                            m.node(93, 94, "y"),
+                                        ]),
                                     }),
-                                    init: m.node(93, 95, Primitive::IntegerNumber("10", NumberUnit::None)),
                                 }),
-                                m.node(117, 140, VariableDefinitionStatement {
-                                    declaration: m.node(117, 132, VariableDeclaration {
+                                m.node(117, 126, ReturnStatement {
+                                    value: m.node(124, 125, "x"),
+                                }),
+                            ]),
+                        }),
+                    }),
+                ]),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn variable_definition_statement() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                function wow() {
+                    uint foo = 10;
+                    bool memory bar = true;
+                    string storage baz;
+                }
+            }
+
+        "#, [
+            m.node(14, 212, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 198, FunctionDefinition {
+                        name: m.node(54, 57, "wow"),
+                        params: NodeList::empty(),
+                        visibility: None,
+                        mutability: None,
+                        is_virtual: None,
+                        overrides: None,
+                        modifiers: NodeList::empty(),
+                        returns: NodeList::empty(),
+                        block: m.node(60, 198, Block {
+                            body: m.list([
+                                m.node(82, 96, VariableDefinitionStatement {
+                                    declaration: m.node(82, 90, VariableDeclaration {
+                                        type_name: m.node(82, 86, ElementaryTypeName::Uint(32)),
+                                        location: None,
+                                        id: m.node(87, 90, "foo"),
+                                    }),
+                                    init: m.node(93, 95, Primitive::IntegerNumber("10", NumberUnit::None)),
+                                }),
+                                m.node(117, 140, VariableDefinitionStatement {
+                                    declaration: m.node(117, 132, VariableDeclaration {
                                         type_name: m.node(117, 121, ElementaryTypeName::Bool),
                                         location: m.node(122, 128, StorageLocation::Memory),
                                         id: m.node(129, 132, "bar"),
@@ -887,6 +1386,117 @@ mod test {
         ]);
     }
 
+    #[test]
+    fn local_reference_types_with_location() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                function wow() {
+                    bytes memory data = msg.data;
+                    uint[] memory list;
+                }
+            }
+
+        "#, [
+            m.node(14, 183, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 169, FunctionDefinition {
+                        name: m.node(54, 57, "wow"),
+                        params: NodeList::empty(),
+                        visibility: None,
+                        mutability: None,
+                        is_virtual: None,
+                        overrides: None,
+                        modifiers: NodeList::empty(),
+                        returns: NodeList::empty(),
+                        block: m.node(60, 169, Block {
+                            body: m.list([
+                                m.node(82, 111, VariableDefinitionStatement {
+                                    declaration: m.node(82, 99, VariableDeclaration {
+                                        type_name: m.node(82, 87, ElementaryTypeName::Bytes),
+                                        location: m.node(88, 94, StorageLocation::Memory),
+                                        id: m.node(95, 99, "data"),
+                                    }),
+                                    init: m.node(102, 110, MemberAccessExpression {
+                                        object: m.node(102, 105, "msg"),
+                                        member: m.node(106, 110, "data"),
+                                    }),
+                                }),
+                                m.node(132, 151, VariableDefinitionStatement {
+                                    declaration: m.node(132, 150, VariableDeclaration {
+                                        type_name: m.node(132, 138, ArrayTypeName {
+                                            type_name: m.node(132, 136, ElementaryTypeName::Uint(32)),
+                                            length: None,
+                                        }),
+                                        location: m.node(139, 145, StorageLocation::Memory),
+                                        id: m.node(146, 150, "list"),
+                                    }),
+                                    init: None,
+                                }),
+                            ]),
+                        }),
+                    }),
+                ]),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn string_with_location() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                function greet(string calldata name) {
+                    string memory s = "hi";
+                }
+            }
+
+        "#, [
+            m.node(14, 159, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 145, FunctionDefinition {
+                        name: m.node(54, 59, "greet"),
+                        params: m.list([
+                            m.node(60, 80, Parameter {
+                                type_name: m.node(60, 66, ElementaryTypeName::String),
+                                location: m.node(67, 75, StorageLocation::Calldata),
+                                name: m.node(76, 80, "name"),
+                            }),
+                        ]),
+                        visibility: None,
+                        mutability: None,
+                        is_virtual: None,
+                        overrides: None,
+                        modifiers: NodeList::empty(),
+                        returns: NodeList::empty(),
+                        block: m.node(82, 145, Block {
+                            body: m.list([
+                                m.node(104, 127, VariableDefinitionStatement {
+                                    declaration: m.node(104, 119, VariableDeclaration {
+                                        type_name: m.node(104, 110, ElementaryTypeName::String),
+                                        location: m.node(111, 117, StorageLocation::Memory),
+                                        id: m.node(118, 119, "s"),
+                                    }),
+                                    init: m.node(122, 126, Primitive::String("\"hi\"")),
+                                }),
+                            ]),
+                        }),
+                    }),
+                ]),
+            }),
+        ]);
+    }
+
     #[test]
     fn inferred_definition_statement() {
         let m = Mock::new();
@@ -904,6 +1514,7 @@ mod test {
 
         "#, [
             m.node(14, 253, ContractDefinition {
+                is_abstract: None,
                 name: m.node(23, 26, "Foo"),
                 inherits: NodeList::empty(),
                 body: m.list([
@@ -912,6 +1523,8 @@ mod test {
                         params: NodeList::empty(),
                         visibility: None,
                         mutability: None,
+                        is_virtual: None,
+                        overrides: None,
                         modifiers: NodeList::empty(),
                         returns: NodeList::empty(),
                         block: m.node(60, 239, Block {
@@ -937,7 +1550,7 @@ mod test {
                                 m.node(163, 175, InferredDefinitionStatement {
                                     ids: List::empty(),
                                     init: m.node(172, 174, TupleExpression {
-                                        expressions: NodeList::empty(),
+                                        expressions: List::empty(),
                                     }),
                                 }),
                                 m.node(196, 221, InferredDefinitionStatement {
@@ -961,4 +1574,370 @@ mod test {
             }),
         ]);
     }
+
+    #[test]
+    fn empty_statement() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                function bar() {
+                    ;
+                    for (;;) {
+                        ;
+                    }
+                }
+            }
+
+        "#, [
+            m.node(14, 194, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 180, FunctionDefinition {
+                        name: m.node(54, 57, "bar"),
+                        params: NodeList::empty(),
+                        visibility: None,
+                        mutability: None,
+                        is_virtual: None,
+                        overrides: None,
+                        modifiers: NodeList::empty(),
+                        returns: NodeList::empty(),
+                        block: m.node(60, 180, Block {
+                            body: m.list([
+                                m.node(82, 83, EmptyStatement),
+                                m.node(104, 162, ForStatement {
+                                    init: None,
+                                    test: None,
+                                    update: None,
+                                    body: m.node(113, 162, Block {
+                                        body: m.list([
+                                            m.node(139, 140, EmptyStatement),
+                                        ]),
+                                    }),
+                                }),
+                            ]),
+                        }),
+                    }),
+                ]),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn try_statement() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                function bar() {
+                    try doge.moon() returns (uint a, bool b) {
+                        a;
+                    } catch Error(string memory reason) {
+                    } catch {
+                    }
+                }
+            }
+
+        "#, [
+            m.node(14, 293, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 279, FunctionDefinition {
+                        name: m.node(54, 57, "bar"),
+                        params: NodeList::empty(),
+                        visibility: None,
+                        mutability: None,
+                        is_virtual: None,
+                        overrides: None,
+                        modifiers: NodeList::empty(),
+                        returns: NodeList::empty(),
+                        block: m.node(60, 279, Block {
+                            body: m.list([
+                                m.node(82, 261, TryStatement {
+                                    expression: m.node(86, 97, CallExpression {
+                                        callee: m.node(86, 95, MemberAccessExpression {
+                                            object: m.node(86, 90, "doge"),
+                                            member: m.node(91, 95, "moon"),
+                                        }),
+                                        arguments: NodeList::empty(),
+                                    }),
+                                    returns: m.list([
+                                        m.node(107, 113, Parameter {
+                                            type_name: m.node(107, 111, ElementaryTypeName::Uint(32)),
+                                            location: None,
+                                            name: m.node(112, 113, "a"),
+                                        }),
+                                        m.node(115, 121, Parameter {
+                                            type_name: m.node(115, 119, ElementaryTypeName::Bool),
+                                            location: None,
+                                            name: m.node(120, 121, "b"),
+                                        }),
+                                    ]),
+                                    body: m.node(123, 173, Block {
+                                        body: m.list([
+                                            m.stmt_expr(149, 150, 151, "a"),
+                                        ]),
+                                    }),
+                                    catches: m.list([
+                                        m.node(174, 231, CatchClause {
+                                            name: m.node(180, 185, "Error"),
+                                            params: m.list([
+                                                m.node(186, 206, Parameter {
+                                                    type_name: m.node(186, 192, ElementaryTypeName::String),
+                                                    location: m.node(193, 199, StorageLocation::Memory),
+                                                    name: m.node(200, 206, "reason"),
+                                                }),
+                                            ]),
+                                            body: m.node(208, 231, Block {
+                                                body: NodeList::empty(),
+                                            }),
+                                        }),
+                                        m.node(232, 261, CatchClause {
+                                            name: None,
+                                            params: NodeList::empty(),
+                                            body: m.node(238, 261, Block {
+                                                body: NodeList::empty(),
+                                            }),
+                                        }),
+                                    ]),
+                                }),
+                            ]),
+                        }),
+                    }),
+                ]),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn try_statement_multiple_catch_clauses() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                function bar() {
+                    try f() {} catch Error(string memory r) {} catch Panic(uint code) {} catch (bytes memory data) {}
+                }
+            }
+
+        "#, [
+            m.node(14, 211, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 197, FunctionDefinition {
+                        name: m.node(54, 57, "bar"),
+                        params: NodeList::empty(),
+                        visibility: None,
+                        mutability: None,
+                        is_virtual: None,
+                        overrides: None,
+                        modifiers: NodeList::empty(),
+                        returns: NodeList::empty(),
+                        block: m.node(60, 197, Block {
+                            body: m.list([
+                                m.node(82, 179, TryStatement {
+                                    expression: m.node(86, 89, CallExpression {
+                                        callee: m.node(86, 87, "f"),
+                                        arguments: NodeList::empty(),
+                                    }),
+                                    returns: NodeList::empty(),
+                                    body: m.node(90, 92, Block {
+                                        body: NodeList::empty(),
+                                    }),
+                                    catches: m.list([
+                                        m.node(93, 124, CatchClause {
+                                            name: m.node(99, 104, "Error"),
+                                            params: m.list([
+                                                m.node(105, 120, Parameter {
+                                                    type_name: m.node(105, 111, ElementaryTypeName::String),
+                                                    location: m.node(112, 118, StorageLocation::Memory),
+                                                    name: m.node(119, 120, "r"),
+                                                }),
+                                            ]),
+                                            body: m.node(122, 124, Block {
+                                                body: NodeList::empty(),
+                                            }),
+                                        }),
+                                        m.node(125, 150, CatchClause {
+                                            name: m.node(131, 136, "Panic"),
+                                            params: m.list([
+                                                m.node(137, 146, Parameter {
+                                                    type_name: m.node(137, 141, ElementaryTypeName::Uint(32)),
+                                                    location: None,
+                                                    name: m.node(142, 146, "code"),
+                                                }),
+                                            ]),
+                                            body: m.node(148, 150, Block {
+                                                body: NodeList::empty(),
+                                            }),
+                                        }),
+                                        m.node(151, 179, CatchClause {
+                                            name: None,
+                                            params: m.list([
+                                                m.node(158, 175, Parameter {
+                                                    type_name: m.node(158, 163, ElementaryTypeName::Bytes),
+                                                    location: m.node(164, 170, StorageLocation::Memory),
+                                                    name: m.node(171, 175, "data"),
+                                                }),
+                                            ]),
+                                            body: m.node(177, 179, Block {
+                                                body: NodeList::empty(),
+                                            }),
+                                        }),
+                                    ]),
+                                }),
+                            ]),
+                        }),
+                    }),
+                ]),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn try_statement_returning_from_body() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                function bar() returns (bool) {
+                    try doge.moon() returns (bool ok) {
+                        return ok;
+                    } catch {
+                        return false;
+                    }
+                }
+            }
+
+        "#, [
+            m.node(14, 289, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 275, FunctionDefinition {
+                        name: m.node(54, 57, "bar"),
+                        params: NodeList::empty(),
+                        visibility: None,
+                        mutability: None,
+                        is_virtual: None,
+                        overrides: None,
+                        modifiers: NodeList::empty(),
+                        returns: m.list([
+                            m.node(69, 73, Parameter {
+                                type_name: m.node(69, 73, ElementaryTypeName::Bool),
+                                location: None,
+                                name: None,
+                            }),
+                        ]),
+                        block: m.node(75, 275, Block {
+                            body: m.list([
+                                m.node(97, 257, TryStatement {
+                                    expression: m.node(101, 112, CallExpression {
+                                        callee: m.node(101, 110, MemberAccessExpression {
+                                            object: m.node(101, 105, "doge"),
+                                            member: m.node(106, 110, "moon"),
+                                        }),
+                                        arguments: NodeList::empty(),
+                                    }),
+                                    returns: m.list([
+                                        m.node(122, 129, Parameter {
+                                            type_name: m.node(122, 126, ElementaryTypeName::Bool),
+                                            location: None,
+                                            name: m.node(127, 129, "ok"),
+                                        }),
+                                    ]),
+                                    body: m.node(131, 189, Block {
+                                        body: m.list([
+                                            m.node(157, 167, ReturnStatement {
+                                                value: m.node(164, 166, "ok"),
+                                            }),
+                                        ]),
+                                    }),
+                                    catches: m.list([
+                                        m.node(190, 257, CatchClause {
+                                            name: None,
+                                            params: NodeList::empty(),
+                                            body: m.node(196, 257, Block {
+                                                body: m.list([
+                                                    m.node(222, 235, ReturnStatement {
+                                                        value: m.node(229, 234, Primitive::Bool(false)),
+                                                    }),
+                                                ]),
+                                            }),
+                                        }),
+                                    ]),
+                                }),
+                            ]),
+                        }),
+                    }),
+                ]),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn emit_statement() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                function bar() {
+                    emit Lib.Event(x);
+                    emit Event();
+                }
+            }
+
+        "#, [
+            m.node(14, 166, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 152, FunctionDefinition {
+                        name: m.node(54, 57, "bar"),
+                        params: NodeList::empty(),
+                        visibility: None,
+                        mutability: None,
+                        is_virtual: None,
+                        overrides: None,
+                        modifiers: NodeList::empty(),
+                        returns: NodeList::empty(),
+                        block: m.node(60, 152, Block {
+                            body: m.list([
+                                m.node(82, 100, EmitStatement {
+                                    call: m.node(87, 99, CallExpression {
+                                        callee: m.node(87, 96, MemberAccessExpression {
+                                            object: m.node(87, 90, "Lib"),
+                                            member: m.node(91, 96, "Event"),
+                                        }),
+                                        arguments: m.list([
+                                            m.node(97, 98, "x"),
+                                        ]),
+                                    }),
+                                }),
+                                m.node(121, 134, EmitStatement {
+                                    call: m.node(126, 133, CallExpression {
+                                        callee: m.node(126, 131, "Event"),
+                                        arguments: NodeList::empty(),
+                                    }),
+                                }),
+                            ]),
+                        }),
+                    }),
+                ]),
+            }),
+        ]);
+    }
 }
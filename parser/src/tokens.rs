@@ -0,0 +1,193 @@
+use std::ops::Range;
+
+use toolshed::NulTermStr;
+use ast::Comment;
+use lexer::{Lexer, Source, Token, TypeSize, SpannedToken, tokenize_with_capacity};
+use comments::gap_comments;
+
+enum Stream<'ast> {
+    /// Tokens are produced by the lexer as the parser advances.
+    Lexer(Lexer<NulTermStr<'ast>>),
+
+    /// All tokens have been lexed into a buffer before parsing started.
+    Buffer {
+        tokens: Vec<SpannedToken>,
+        index: usize,
+    },
+}
+
+/// Source of tokens for the parser. Mirrors the interface of the lexer,
+/// so the parser doesn't need to care whether the source is being lexed
+/// on the fly or read from a flat buffer of tokens.
+pub struct Tokens<'ast> {
+    /// Current token
+    pub token: Token,
+
+    /// Size of the current token, if it's a sized elementary type
+    pub extras: TypeSize,
+
+    /// Source the tokens are being read from
+    pub source: NulTermStr<'ast>,
+
+    stream: Stream<'ast>,
+
+    /// Comments found between tokens so far, if they are being collected
+    comments: Option<Vec<Comment>>,
+
+    /// End of the previous token, the next comment can't start before it
+    last_end: usize,
+}
+
+impl<'ast> Tokens<'ast> {
+    /// Lex the source as the parser advances.
+    pub fn new(source: NulTermStr<'ast>) -> Self {
+        let lexer = Lexer::new(source);
+
+        Tokens {
+            token: lexer.token,
+            extras: lexer.extras,
+            source,
+            stream: Stream::Lexer(lexer),
+            comments: None,
+            last_end: 0,
+        }
+    }
+
+    /// Lex the entire source into a buffer with room for `capacity` tokens,
+    /// the parser will then only be reading from it.
+    pub fn buffered(source: NulTermStr<'ast>, capacity: usize) -> Self {
+        let mut tokens = tokenize_with_capacity(&source, capacity);
+
+        // The parser expects to end on `EndOfProgram`, unless the source
+        // ended abruptly, which the tokens collected would already end on.
+        match tokens.last().map(|token| token.token) {
+            Some(Token::UnexpectedEndOfProgram) => {},
+            _ => {
+                let end = source.len();
+
+                tokens.push(SpannedToken {
+                    token: Token::EndOfProgram,
+                    span: end..end,
+                    extras: TypeSize::default(),
+                });
+            },
+        }
+
+        Tokens {
+            token: tokens[0].token,
+            extras: tokens[0].extras,
+            source,
+            stream: Stream::Buffer { tokens, index: 0 },
+            comments: None,
+            last_end: 0,
+        }
+    }
+
+    #[inline]
+    pub fn advance(&mut self) {
+        match self.stream {
+            Stream::Lexer(ref mut lexer) => {
+                lexer.advance();
+
+                self.token  = lexer.token;
+                self.extras = lexer.extras;
+            },
+            Stream::Buffer { ref tokens, ref mut index } => {
+                // The last token always ends the program, stay on it
+                if *index + 1 < tokens.len() {
+                    *index += 1;
+                }
+
+                self.token  = tokens[*index].token;
+                self.extras = tokens[*index].extras;
+            },
+        }
+
+        if self.comments.is_some() {
+            self.scan_comments();
+        }
+    }
+
+    #[inline]
+    pub fn range(&self) -> Range<usize> {
+        match self.stream {
+            Stream::Lexer(ref lexer) => lexer.range(),
+            Stream::Buffer { ref tokens, index } => {
+                tokens[index].span.clone()
+            },
+        }
+    }
+
+    #[inline]
+    pub fn slice(&self) -> &'ast str {
+        match self.stream {
+            Stream::Lexer(ref lexer) => lexer.slice(),
+            Stream::Buffer { .. } => {
+                self.source.slice(self.range()).expect("Buffered tokens are within bounds; qed")
+            },
+        }
+    }
+
+    /// Read the raw source of a pragma, starting at the current token
+    /// and up to the terminating semicolon.
+    pub fn read_pragma(&mut self) -> &'ast str {
+        let (start, end) = match self.stream {
+            Stream::Lexer(ref mut lexer) => {
+                let pragma = ::lexer::read_pragma(lexer);
+
+                self.token  = lexer.token;
+                self.extras = lexer.extras;
+
+                if self.comments.is_some() {
+                    self.scan_comments();
+                }
+
+                return pragma;
+            },
+            Stream::Buffer { ref tokens, ref mut index } => {
+                let start = tokens[*index].span.start;
+
+                while tokens[*index].token != Token::Semicolon && *index + 1 < tokens.len() {
+                    *index += 1;
+                }
+
+                let current = &tokens[*index];
+
+                self.token = match current.token {
+                    Token::Semicolon => Token::Semicolon,
+                    _                => Token::UnexpectedEndOfProgram,
+                };
+                self.extras = current.extras;
+
+                (start, current.span.start)
+            },
+        };
+
+        if self.comments.is_some() {
+            self.scan_comments();
+        }
+
+        self.source.slice(start..end).expect("Buffered tokens are within bounds; qed")
+    }
+
+    /// Start collecting comments found between tokens as they are read.
+    pub fn collect_comments(&mut self) {
+        self.comments = Some(Vec::new());
+        self.scan_comments();
+    }
+
+    /// Take all comments collected so far.
+    pub fn take_comments(&mut self) -> Vec<Comment> {
+        self.comments.take().unwrap_or_default()
+    }
+
+    fn scan_comments(&mut self) {
+        let range = self.range();
+
+        if let Some(ref mut comments) = self.comments {
+            gap_comments(&self.source, self.last_end, range.start, comments);
+        }
+
+        self.last_end = range.end;
+    }
+}
@@ -1,9 +1,9 @@
 use ast::*;
-use Parser;
+use {Parser, TOP};
 use lexer::Token;
 
 pub trait TypeNameContext<'ast> {
-    fn parse(&mut Parser<'ast>) -> Option<TypeNameNode<'ast>>;
+    fn parse(par: &mut Parser<'ast>) -> Option<TypeNameNode<'ast>>;
 }
 
 pub struct RegularTypeNameContext;
@@ -12,9 +12,10 @@ pub struct StatementTypeNameContext;
 impl<'ast> TypeNameContext<'ast> for RegularTypeNameContext {
     fn parse(par: &mut Parser<'ast>) -> Option<TypeNameNode<'ast>> {
         match par.lexer.token {
-            Token::KeywordMapping => par.mapping(),
-            Token::Identifier     => par.user_defined_type(),
-            _                     => par.elementary_type_name(),
+            Token::KeywordMapping      => par.mapping(),
+            Token::Identifier          => par.user_defined_type(),
+            Token::DeclarationFunction => par.function_type_name(),
+            _                          => par.elementary_type_name(),
         }
     }
 }
@@ -35,19 +36,50 @@ impl<'ast> Parser<'ast> {
     where
         Context: TypeNameContext<'ast>,
     {
-        Context::parse(self)
+        let mut type_name = Context::parse(self)?;
+
+        while self.allow(Token::BracketOpen) {
+            let length = self.expression(TOP);
+            let end    = self.expect_end(Token::BracketClose);
+
+            if let Some(length) = length {
+                self.reject_negative_length(length);
+            }
+
+            type_name = self.node_at(type_name.start, end, ArrayTypeName {
+                type_name,
+                length,
+            });
+        }
+
+        Some(type_name)
+    }
+
+    /// Array length is an expression, so a negated literal would otherwise
+    /// parse just fine. Point the error at the minus sign.
+    fn reject_negative_length(&mut self, length: ExpressionNode<'ast>) {
+        if let Expression::PrefixExpression(prefix) = length.value {
+            if prefix.operator.value == PrefixOperator::Minus {
+                let span = prefix.operator.start as usize..prefix.operator.end as usize;
+
+                self.error_at(Token::OperatorSubtraction, span);
+            }
+        }
     }
 
     pub fn elementary_type_name<E>(&mut self) -> Option<Node<'ast, E>>
     where
         E: From<ElementaryTypeName> + Copy,
     {
+        if self.lexer.token == Token::TypeAddress {
+            return self.address_type_name();
+        }
+
         let elementary = {
-            let ref size = self.lexer.extras;
+            let size = &self.lexer.extras;
 
             match self.lexer.token {
                 Token::TypeBool       => ElementaryTypeName::Bool,
-                Token::TypeAddress    => ElementaryTypeName::Address,
                 Token::TypeString     => ElementaryTypeName::String,
                 Token::TypeByte       => ElementaryTypeName::Byte(size.0),
                 Token::TypeBytes      => ElementaryTypeName::Bytes,
@@ -62,17 +94,31 @@ impl<'ast> Parser<'ast> {
         self.node_at_token(elementary)
     }
 
+    fn address_type_name<E>(&mut self) -> Option<Node<'ast, E>>
+    where
+        E: From<ElementaryTypeName> + Copy,
+    {
+        let (start, end) = self.loc();
+
+        self.lexer.advance();
+
+        match self.lexer.token {
+            Token::KeywordPayable => {
+                let end = self.end_then_advance();
+
+                self.node_at(start, end, ElementaryTypeName::AddressPayable)
+            },
+            _ => self.node_at(start, end, ElementaryTypeName::Address),
+        }
+    }
+
     pub fn variable_declaration<Context>(&mut self) -> Option<VariableDeclarationNode<'ast>>
     where
         Context: TypeNameContext<'ast>,
     {
         let type_name = self.type_name::<Context>()?;
 
-        let location = match self.lexer.token {
-            Token::KeywordStorage => self.node_at_token(StorageLocation::Storage),
-            Token::KeywordMemory  => self.node_at_token(StorageLocation::Memory),
-            _                     => None,
-        };
+        let location = self.storage_location();
 
         let id = self.expect_str_node(Token::Identifier);
 
@@ -83,6 +129,15 @@ impl<'ast> Parser<'ast> {
         })
     }
 
+    pub fn storage_location(&mut self) -> Option<Node<'ast, StorageLocation>> {
+        match self.lexer.token {
+            Token::KeywordStorage  => self.node_at_token(StorageLocation::Storage),
+            Token::KeywordMemory   => self.node_at_token(StorageLocation::Memory),
+            Token::KeywordCalldata => self.node_at_token(StorageLocation::Calldata),
+            _                      => None,
+        }
+    }
+
     fn user_defined_type(&mut self) -> Option<TypeNameNode<'ast>> {
         let (start, end) = self.loc();
         let identifier = self.lexer.slice();
@@ -92,21 +147,241 @@ impl<'ast> Parser<'ast> {
         self.node_at(start, end, identifier)
     }
 
+    fn function_type_name(&mut self) -> Option<TypeNameNode<'ast>> {
+        let start = self.start_then_advance();
+
+        self.expect(Token::ParenOpen);
+
+        let params  = self.parameter_list();
+        let mut end = self.expect_end(Token::ParenClose);
+
+        let mut visibility = None;
+        let mut mutability = None;
+
+        // Unlike in function definitions, an identifier here is the name
+        // of the variable, not a modifier, so only flags are consumed.
+        loop {
+            let flag_end = self.lexer.range().end as u32;
+
+            match self.lexer.token {
+                Token::KeywordExternal => self.unique_flag(&mut visibility, FunctionVisibility::External),
+                Token::KeywordInternal => self.unique_flag(&mut visibility, FunctionVisibility::Internal),
+
+                Token::KeywordPure     => self.unique_flag(&mut mutability, StateMutability::Pure),
+                Token::KeywordConstant => self.unique_flag(&mut mutability, StateMutability::Constant),
+                Token::KeywordView     => self.unique_flag(&mut mutability, StateMutability::View),
+                Token::KeywordPayable  => self.unique_flag(&mut mutability, StateMutability::Payable),
+
+                _ => break,
+            }
+
+            end = flag_end;
+        }
+
+        let returns = if self.allow(Token::KeywordReturns) {
+            self.expect(Token::ParenOpen);
+
+            let returns = self.parameter_list();
+
+            end = self.expect_end(Token::ParenClose);
+
+            returns
+        } else {
+            NodeList::empty()
+        };
+
+        self.node_at(start, end, FunctionTypeName {
+            params,
+            visibility,
+            mutability,
+            returns,
+        })
+    }
+
     fn mapping(&mut self) -> Option<TypeNameNode<'ast>> {
         let start = self.start_then_advance();
 
         self.expect(Token::ParenOpen);
 
-        let from = expect!(self, self.elementary_type_name());
+        let from      = expect!(self, self.type_name::<RegularTypeNameContext>());
+        let from_name = self.allow_str_node(Token::Identifier);
 
         self.expect(Token::Arrow);
 
-        let to  = expect!(self, self.type_name::<RegularTypeNameContext>());
-        let end = self.expect_end(Token::ParenClose);
+        let to      = expect!(self, self.type_name::<RegularTypeNameContext>());
+        let to_name = self.allow_str_node(Token::Identifier);
+        let end     = self.expect_end(Token::ParenClose);
 
         self.node_at(start, end, Mapping {
             from,
+            from_name,
             to,
+            to_name,
         })
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use mock::{Mock, assert_units, parse_err};
+
+    #[test]
+    fn mapping() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                mapping(address => uint256) balances;
+                mapping(address owner => uint256 balance) named;
+            }
+
+        "#, [
+            m.node(14, 161, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 82, StateVariableDeclaration {
+                        type_name: m.node(45, 72, Mapping {
+                            from: m.node(53, 60, ElementaryTypeName::Address),
+                            from_name: None,
+                            to: m.node(64, 71, ElementaryTypeName::Uint(32)),
+                            to_name: None,
+                        }),
+                        visibility: None,
+                        constant: None,
+                        name: m.node(73, 81, "balances"),
+                        init: None,
+                    }),
+                    m.node(99, 147, StateVariableDeclaration {
+                        type_name: m.node(99, 140, Mapping {
+                            from: m.node(107, 114, ElementaryTypeName::Address),
+                            from_name: m.node(115, 120, "owner"),
+                            to: m.node(124, 131, ElementaryTypeName::Uint(32)),
+                            to_name: m.node(132, 139, "balance"),
+                        }),
+                        visibility: None,
+                        constant: None,
+                        name: m.node(141, 146, "named"),
+                        init: None,
+                    }),
+                ]),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn user_defined_mapping() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                mapping(uint => MyStruct) structs;
+                mapping(Token => bool) tokens;
+            }
+
+        "#, [
+            m.node(14, 140, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 79, StateVariableDeclaration {
+                        type_name: m.node(45, 70, Mapping {
+                            from: m.node(53, 57, ElementaryTypeName::Uint(32)),
+                            from_name: None,
+                            to: m.node(61, 69, "MyStruct"),
+                            to_name: None,
+                        }),
+                        visibility: None,
+                        constant: None,
+                        name: m.node(71, 78, "structs"),
+                        init: None,
+                    }),
+                    m.node(96, 126, StateVariableDeclaration {
+                        type_name: m.node(96, 118, Mapping {
+                            from: m.node(104, 109, "Token"),
+                            from_name: None,
+                            to: m.node(113, 117, ElementaryTypeName::Bool),
+                            to_name: None,
+                        }),
+                        visibility: None,
+                        constant: None,
+                        name: m.node(119, 125, "tokens"),
+                        init: None,
+                    }),
+                ]),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn array_type_name() {
+        let m = Mock::new();
+
+        assert_units(r#"
+
+            contract Foo {
+                mapping(uint => uint[]) lists;
+                bytes32[10][] matrix;
+            }
+
+        "#, [
+            m.node(14, 127, ContractDefinition {
+                is_abstract: None,
+                name: m.node(23, 26, "Foo"),
+                inherits: NodeList::empty(),
+                body: m.list([
+                    m.node(45, 75, StateVariableDeclaration {
+                        type_name: m.node(45, 68, Mapping {
+                            from: m.node(53, 57, ElementaryTypeName::Uint(32)),
+                            from_name: None,
+                            to: m.node(61, 67, ArrayTypeName {
+                                type_name: m.node(61, 65, ElementaryTypeName::Uint(32)),
+                                length: None,
+                            }),
+                            to_name: None,
+                        }),
+                        visibility: None,
+                        constant: None,
+                        name: m.node(69, 74, "lists"),
+                        init: None,
+                    }),
+                    m.node(92, 113, StateVariableDeclaration {
+                        type_name: m.node(92, 105, ArrayTypeName {
+                            type_name: m.node(92, 103, ArrayTypeName {
+                                type_name: m.node(92, 99, ElementaryTypeName::Byte(32)),
+                                length: m.node(100, 102, Primitive::IntegerNumber("10", NumberUnit::None)),
+                            }),
+                            length: None,
+                        }),
+                        visibility: None,
+                        constant: None,
+                        name: m.node(106, 112, "matrix"),
+                        init: None,
+                    }),
+                ]),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn negative_array_length() {
+        use parse;
+        use error::Error;
+
+        let errors = parse_err("contract Foo { uint[-1] a; }");
+
+        assert_eq!(errors, vec![Error {
+            token: Token::OperatorSubtraction,
+            raw: "-".into(),
+            span: 20..21,
+            since: None,
+        }]);
+
+        assert!(parse("contract Foo { uint[3] a; }").is_ok());
+    }
+}
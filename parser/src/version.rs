@@ -0,0 +1,32 @@
+use std::fmt;
+
+/// Version of the Solidity compiler, used to reject syntax that isn't
+/// supported by the targeted version.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Version {
+    pub major: u8,
+    pub minor: u8,
+    pub patch: u8,
+}
+
+impl Version {
+    pub const fn new(major: u8, minor: u8, patch: u8) -> Self {
+        Version {
+            major,
+            minor,
+            patch,
+        }
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// `unchecked { ... }` blocks
+pub const UNCHECKED_BLOCKS: Version = Version::new(0, 8, 0);
+
+/// `error Name(...);` definitions
+pub const CUSTOM_ERRORS: Version = Version::new(0, 8, 4);